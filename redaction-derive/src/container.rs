@@ -4,11 +4,22 @@
 
 use syn::{Attribute, Meta, Result};
 
+use crate::strategy::classification_path;
+
 /// Options parsed from container-level `#[sensitive(...)]` attributes.
 #[derive(Clone, Debug, Default)]
 pub(crate) struct ContainerOptions {
     /// If true, skip generating the `Debug` impl.
     pub(crate) skip_debug: bool,
+    /// Classification applied to unannotated string-like fields
+    /// (`#[sensitive(default = Class)]`).
+    pub(crate) default_classification: Option<syn::Path>,
+    /// If true, every field must be covered by an annotation or the container
+    /// default (`#[sensitive(deny_unannotated)]`).
+    pub(crate) deny_unannotated: bool,
+    /// If true, unannotated fields with sensitive-looking names are errors
+    /// rather than warnings (`#[sensitive(strict_names)]`).
+    pub(crate) strict_names: bool,
 }
 
 /// Parses container-level `#[sensitive(...)]` attributes.
@@ -30,9 +41,23 @@ pub(crate) fn parse_container_options(attrs: &[Attribute]) -> Result<ContainerOp
                     if meta.path.is_ident("skip_debug") {
                         options.skip_debug = true;
                         Ok(())
+                    } else if meta.path.is_ident("deny_unannotated") {
+                        options.deny_unannotated = true;
+                        Ok(())
+                    } else if meta.path.is_ident("strict_names") {
+                        options.strict_names = true;
+                        Ok(())
+                    } else if meta.path.is_ident("default") {
+                        if options.default_classification.is_some() {
+                            return Err(meta.error("duplicate container option `default`"));
+                        }
+                        let path: syn::Path = meta.value()?.parse()?;
+                        options.default_classification = Some(classification_path(path)?);
+                        Ok(())
                     } else {
                         Err(meta.error(format!(
-                            "unknown container option `{}`; expected `skip_debug`",
+                            "unknown container option `{}`; expected `skip_debug`, \
+                             `deny_unannotated`, `strict_names`, or `default = Classification`",
                             meta.path
                                 .get_ident()
                                 .map_or_else(|| "?".to_string(), ToString::to_string)
@@ -73,6 +98,41 @@ mod tests {
         let attrs = parse_attrs(quote! {});
         let options = parse_container_options(&attrs).unwrap();
         assert!(!options.skip_debug);
+        assert!(options.default_classification.is_none());
+    }
+
+    #[test]
+    fn default_classification_is_parsed() {
+        let attrs = parse_attrs(quote! { #[sensitive(default = Pii, skip_debug)] });
+        let options = parse_container_options(&attrs).unwrap();
+        assert!(options.skip_debug);
+        assert!(options.default_classification.unwrap().is_ident("Pii"));
+    }
+
+    #[test]
+    fn deny_unannotated_is_parsed() {
+        let attrs = parse_attrs(quote! { #[sensitive(deny_unannotated)] });
+        let options = parse_container_options(&attrs).unwrap();
+        assert!(options.deny_unannotated);
+        assert!(!options.skip_debug);
+    }
+
+    #[test]
+    fn strict_names_is_parsed() {
+        let attrs = parse_attrs(quote! { #[sensitive(strict_names)] });
+        let options = parse_container_options(&attrs).unwrap();
+        assert!(options.strict_names);
+        assert!(!options.deny_unannotated);
+    }
+
+    #[test]
+    fn duplicate_default_errors() {
+        let attrs = parse_attrs(quote! { #[sensitive(default = Pii, default = Secret)] });
+        let result = parse_container_options(&attrs);
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("duplicate container option"));
     }
 
     #[test]
@@ -8,8 +8,9 @@ use quote::{format_ident, quote, quote_spanned};
 use syn::{spanned::Spanned, DataEnum, Fields, Result};
 
 use crate::{
+    container::ContainerOptions,
     crate_path,
-    strategy::{parse_field_strategy, Strategy},
+    strategy::resolve_field_strategy,
     transform::{generate_field_transform, DeriveContext},
 };
 
@@ -36,6 +37,7 @@ pub(crate) fn derive_enum(
     name: &Ident,
     data: DataEnum,
     generics: &syn::Generics,
+    options: &ContainerOptions,
 ) -> Result<EnumDeriveOutput> {
     let container_path = crate_path("SensitiveType");
     let mut arms = Vec::new();
@@ -57,6 +59,7 @@ pub(crate) fn derive_enum(
         };
         let mut derive_ctx = DeriveContext {
             generics,
+            options,
             container_path: &container_path,
             used_generics: &mut used_generics,
             classified_generics: &mut classified_generics,
@@ -135,13 +138,13 @@ fn derive_named_variant(
 
     for field in fields.named {
         let span = field.span();
-        let strategy = parse_field_strategy(&field.attrs)?;
+        let strategy = resolve_field_strategy(&field, derive_ctx.options)?;
         let ident = field.ident.expect("named field should have an identifier");
         let binding = ident.clone();
         let ty = &field.ty;
         bindings.push(ident);
 
-        let is_sensitive = matches!(&strategy, Strategy::Classify(_) | Strategy::Walk);
+        let is_sensitive = strategy.is_sensitive();
         let transform = generate_field_transform(derive_ctx, ty, &binding, span, &strategy)?;
 
         let debug_redacted_field = if is_sensitive {
@@ -204,10 +207,10 @@ fn derive_unnamed_variant(
         let binding = ident.clone();
         let span = field.span();
         let ty = &field.ty;
-        let strategy = parse_field_strategy(&field.attrs)?;
+        let strategy = resolve_field_strategy(&field, derive_ctx.options)?;
         bindings.push(ident);
 
-        let is_sensitive = matches!(&strategy, Strategy::Classify(_) | Strategy::Walk);
+        let is_sensitive = strategy.is_sensitive();
         let transform = generate_field_transform(derive_ctx, ty, &binding, span, &strategy)?;
 
         let debug_redacted_field = if is_sensitive {
@@ -8,8 +8,9 @@ use quote::{format_ident, quote, quote_spanned};
 use syn::{spanned::Spanned, DataStruct, Fields, Result};
 
 use crate::{
+    container::ContainerOptions,
     crate_path,
-    strategy::{parse_field_strategy, Strategy},
+    strategy::resolve_field_strategy,
     transform::{generate_field_transform, DeriveContext},
 };
 
@@ -37,6 +38,7 @@ pub(crate) fn derive_struct(
     name: &Ident,
     data: DataStruct,
     generics: &syn::Generics,
+    options: &ContainerOptions,
 ) -> Result<StructDeriveOutput> {
     let container_path = crate_path("SensitiveType");
     let StructParts {
@@ -48,8 +50,12 @@ pub(crate) fn derive_struct(
         debug_unredacted_body,
         debug_unredacted_generics,
     } = match data.fields {
-        Fields::Named(fields) => derive_named_struct(name, fields, generics, &container_path)?,
-        Fields::Unnamed(fields) => derive_unnamed_struct(name, fields, generics, &container_path)?,
+        Fields::Named(fields) => {
+            derive_named_struct(name, fields, generics, options, &container_path)?
+        }
+        Fields::Unnamed(fields) => {
+            derive_unnamed_struct(name, fields, generics, options, &container_path)?
+        }
         Fields::Unit => StructParts {
             redaction_body: quote! { self },
             used_generics: Vec::new(),
@@ -80,6 +86,7 @@ fn derive_named_struct(
     name: &Ident,
     fields: syn::FieldsNamed,
     generics: &syn::Generics,
+    options: &ContainerOptions,
     container_path: &TokenStream,
 ) -> Result<StructParts> {
     let mut bindings = Vec::new();
@@ -93,6 +100,7 @@ fn derive_named_struct(
 
     let mut ctx = DeriveContext {
         generics,
+        options,
         container_path,
         used_generics: &mut used_generics,
         classified_generics: &mut classified_generics,
@@ -102,13 +110,13 @@ fn derive_named_struct(
 
     for field in fields.named {
         let span = field.span();
-        let strategy = parse_field_strategy(&field.attrs)?;
+        let strategy = resolve_field_strategy(&field, options)?;
         let ident = field.ident.expect("named field should have an identifier");
         let binding = ident.clone();
         let ty = &field.ty;
         bindings.push(ident);
 
-        let is_sensitive = matches!(&strategy, Strategy::Classify(_) | Strategy::Walk);
+        let is_sensitive = strategy.is_sensitive();
         let transform = generate_field_transform(&mut ctx, ty, &binding, span, &strategy)?;
 
         let debug_redacted_field = if is_sensitive {
@@ -164,6 +172,7 @@ fn derive_unnamed_struct(
     name: &Ident,
     fields: syn::FieldsUnnamed,
     generics: &syn::Generics,
+    options: &ContainerOptions,
     container_path: &TokenStream,
 ) -> Result<StructParts> {
     let mut bindings = Vec::new();
@@ -177,6 +186,7 @@ fn derive_unnamed_struct(
 
     let mut ctx = DeriveContext {
         generics,
+        options,
         container_path,
         used_generics: &mut used_generics,
         classified_generics: &mut classified_generics,
@@ -189,10 +199,10 @@ fn derive_unnamed_struct(
         let binding = ident.clone();
         let span = field.span();
         let ty = &field.ty;
-        let strategy = parse_field_strategy(&field.attrs)?;
+        let strategy = resolve_field_strategy(&field, options)?;
         bindings.push(ident);
 
-        let is_sensitive = matches!(&strategy, Strategy::Classify(_) | Strategy::Walk);
+        let is_sensitive = strategy.is_sensitive();
         let transform = generate_field_transform(&mut ctx, ty, &binding, span, &strategy)?;
 
         let debug_redacted_field = if is_sensitive {
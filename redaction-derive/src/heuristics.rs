@@ -0,0 +1,213 @@
+//! Name-based detection of likely-sensitive fields.
+//!
+//! Unannotated fields pass through unchanged, so a forgotten
+//! `#[sensitive(...)]` on `password: String` leaks silently. This module flags
+//! unannotated string-like fields whose names suggest sensitive data and
+//! suggests a classification.
+//!
+//! Proc macros cannot emit warnings on stable Rust, so each finding is emitted
+//! as a use of a `#[deprecated]` constant spanned at the field; the compiler
+//! then reports the deprecation note as a warning there. That also means
+//! `-A deprecated` silences the findings. Under
+//! `#[sensitive(strict_names)]` findings are compile errors instead.
+
+use proc_macro2::TokenStream;
+use quote::quote_spanned;
+use syn::{spanned::Spanned, Data, Result};
+
+use crate::{
+    container::ContainerOptions, strategy::parse_field_strategy, types::is_string_like_type,
+};
+
+/// Name fragments that suggest sensitive data, with the suggested classification.
+///
+/// Fragments match whole `snake_case` segments (`user_password`, `api_key_id`),
+/// never parts of a segment (`tokenizer` does not match `token`).
+const SENSITIVE_NAMES: &[(&str, &str)] = &[
+    ("password", "Secret"),
+    ("passwd", "Secret"),
+    ("passphrase", "Secret"),
+    ("secret", "Secret"),
+    ("private_key", "Secret"),
+    ("cvv", "Secret"),
+    ("api_key", "Token"),
+    ("apikey", "Token"),
+    ("token", "Token"),
+    ("session_id", "SessionId"),
+    ("ssn", "NationalId"),
+    ("national_id", "NationalId"),
+    ("email", "Email"),
+    ("card_number", "CreditCard"),
+    ("credit_card", "CreditCard"),
+    ("phone", "PhoneNumber"),
+    ("date_of_birth", "DateOfBirth"),
+    ("dob", "DateOfBirth"),
+];
+
+/// Trailing segments that describe metadata about a value rather than the
+/// value itself (`token_type`, `password_length`).
+const METADATA_SUFFIXES: &[&str] = &["type", "kind", "count", "len", "length", "format", "expiry"];
+
+/// Returns the classification suggested for a field named `name`, if any.
+pub(crate) fn suggested_classification(name: &str) -> Option<&'static str> {
+    let name = name.trim_start_matches("r#").to_ascii_lowercase();
+    let segments: Vec<&str> = name.split('_').filter(|s| !s.is_empty()).collect();
+    if segments
+        .last()
+        .is_some_and(|last| METADATA_SUFFIXES.contains(last))
+    {
+        return None;
+    }
+    SENSITIVE_NAMES
+        .iter()
+        .find_map(|(fragment, classification)| {
+            let parts: Vec<&str> = fragment.split('_').collect();
+            segments
+                .windows(parts.len())
+                .any(|window| window == parts.as_slice())
+                .then_some(*classification)
+        })
+}
+
+/// Checks every named field of `data` and returns the warning tokens to emit.
+///
+/// Fields with any `#[sensitive]` attribute (including `#[sensitive(public)]`)
+/// and fields covered by a container `default` are never flagged.
+pub(crate) fn check_field_names(data: &Data, options: &ContainerOptions) -> Result<TokenStream> {
+    let fields: Vec<&syn::Field> = match data {
+        Data::Struct(data) => data.fields.iter().collect(),
+        Data::Enum(data) => data
+            .variants
+            .iter()
+            .flat_map(|variant| variant.fields.iter())
+            .collect(),
+        Data::Union(_) => Vec::new(),
+    };
+
+    let mut warnings = TokenStream::new();
+    let mut errors: Option<syn::Error> = None;
+    for field in fields {
+        let Some(ident) = &field.ident else {
+            continue;
+        };
+        if options.default_classification.is_some()
+            || !is_string_like_type(&field.ty)
+            || parse_field_strategy(&field.attrs)?.is_some()
+        {
+            continue;
+        }
+        let name = ident.to_string();
+        let Some(classification) = suggested_classification(&name) else {
+            continue;
+        };
+        let name = name.trim_start_matches("r#");
+
+        let message = format!(
+            "field `{name}` looks sensitive but has no #[sensitive] annotation; \
+            consider #[sensitive({classification})], or #[sensitive(public)] if it is not sensitive"
+        );
+        if options.strict_names {
+            let error = syn::Error::new(field.span(), message);
+            match &mut errors {
+                Some(existing) => existing.combine(error),
+                None => errors = Some(error),
+            }
+        } else {
+            warnings.extend(quote_spanned! { ident.span() =>
+                const _: () = {
+                    #[deprecated(note = #message)]
+                    #[allow(non_upper_case_globals)]
+                    const unannotated_sensitive_field: () = ();
+                    unannotated_sensitive_field
+                };
+            });
+        }
+    }
+
+    match errors {
+        Some(error) => Err(error),
+        None => Ok(warnings),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quote::quote;
+    use syn::DeriveInput;
+
+    use super::*;
+
+    fn parse_data(tokens: proc_macro2::TokenStream) -> Data {
+        let input: DeriveInput = syn::parse2(tokens).expect("should parse as DeriveInput");
+        input.data
+    }
+
+    #[test]
+    fn suggests_classifications_for_sensitive_names() {
+        assert_eq!(suggested_classification("password"), Some("Secret"));
+        assert_eq!(
+            suggested_classification("user_password_hash"),
+            Some("Secret")
+        );
+        assert_eq!(suggested_classification("API_KEY"), Some("Token"));
+        assert_eq!(suggested_classification("customer_email"), Some("Email"));
+        assert_eq!(suggested_classification("card_number"), Some("CreditCard"));
+        assert_eq!(suggested_classification("r#ssn"), Some("NationalId"));
+    }
+
+    #[test]
+    fn ignores_partial_and_unrelated_names() {
+        assert_eq!(suggested_classification("tokenizer"), None);
+        assert_eq!(suggested_classification("card"), None);
+        assert_eq!(suggested_classification("username"), None);
+        assert_eq!(suggested_classification("emails_sent"), None);
+        assert_eq!(suggested_classification("token_type"), None);
+        assert_eq!(suggested_classification("password_length"), None);
+    }
+
+    #[test]
+    fn warns_only_for_unannotated_string_fields() {
+        let data = parse_data(quote! {
+            struct Login {
+                password: String,
+                #[sensitive(public)]
+                email: String,
+                #[sensitive(Secret)]
+                secret: String,
+                token_count: u32,
+            }
+        });
+        let warnings = check_field_names(&data, &ContainerOptions::default()).unwrap();
+        let warnings = warnings.to_string();
+        assert_eq!(warnings.matches("deprecated").count(), 1);
+        assert!(warnings.contains("field `password` looks sensitive"));
+    }
+
+    #[test]
+    fn container_default_suppresses_warnings() {
+        let data = parse_data(quote! { struct Login { password: String } });
+        let options = ContainerOptions {
+            default_classification: Some(syn::parse_quote!(Secret)),
+            ..ContainerOptions::default()
+        };
+        assert!(check_field_names(&data, &options).unwrap().is_empty());
+    }
+
+    #[test]
+    fn strict_names_turns_findings_into_errors() {
+        let data = parse_data(quote! {
+            enum Credential {
+                Password { password: String },
+                Key { api_key: Option<String> },
+            }
+        });
+        let options = ContainerOptions {
+            strict_names: true,
+            ..ContainerOptions::default()
+        };
+        let err = check_field_names(&data, &options).unwrap_err();
+        let messages: Vec<String> = err.into_iter().map(|e| e.to_string()).collect();
+        assert_eq!(messages.len(), 2);
+        assert!(messages[1].contains("#[sensitive(Token)]"));
+    }
+}
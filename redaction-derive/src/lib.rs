@@ -78,17 +78,19 @@ mod container;
 mod derive_enum;
 mod derive_struct;
 mod generics;
+mod heuristics;
 mod redacted_display;
 mod strategy;
 mod transform;
 mod types;
-use container::{parse_container_options, ContainerOptions};
+use container::parse_container_options;
 use derive_enum::derive_enum;
 use derive_struct::derive_struct;
 use generics::{
     add_classified_value_bounds, add_clone_bounds, add_container_bounds, add_debug_bounds,
     add_display_bounds, add_redacted_display_bounds,
 };
+use heuristics::check_field_names;
 use redacted_display::derive_redacted_display;
 
 /// Derives `redaction::SensitiveType` (and related impls) for structs and enums.
@@ -99,6 +101,22 @@ use redacted_display::derive_redacted_display;
 ///
 /// - `#[sensitive(skip_debug)]` - Opt out of `Debug` impl generation. Use this when you need a
 ///   custom `Debug` implementation or the type already derives `Debug` elsewhere.
+/// - `#[sensitive(default = Classification)]` - Classify every unannotated string-like field
+///   (`String`, `Cow<str>`, and `Option`/`Vec`/`Box`/set/map-value wrappers of them). Field
+///   annotations take precedence.
+/// - `#[sensitive(deny_unannotated)]` - Reject fields that would silently pass through: every
+///   field needs an explicit annotation (`#[sensitive(public)]` for non-sensitive data) unless a
+///   `default` classification covers it.
+/// - `#[sensitive(strict_names)]` - Turn the name-based warnings below into compile errors.
+///
+/// # Name Heuristics
+///
+/// Unannotated string-like fields named like sensitive data (`password`, `secret`, `token`,
+/// `api_key`, `ssn`, `email`, `card_number`, ...) produce a compile-time warning that suggests a
+/// classification. Proc macros cannot emit warnings directly on stable Rust, so the warning is
+/// reported as the use of a deprecated constant. Mark the field `#[sensitive(public)]` to silence
+/// it. Allowing the `deprecated` lint (for example `-A deprecated`) hides these warnings as well;
+/// use `strict_names` where that lint is allowed.
 ///
 /// # Field Attributes
 ///
@@ -107,15 +125,42 @@ use redacted_display::derive_redacted_display;
 ///
 /// - `#[sensitive]`: For scalar types (i32, bool, char, etc.), redacts to default values (0, false,
 ///   'X'). For struct/enum types that derive `Sensitive`, walks into them using `SensitiveType`.
+///   `Arc<Mutex<T>>` and `Arc<RwLock<T>>` are recognized by their syntax and redacted through
+///   `redact_arc_mutex_with` / `redact_arc_rwlock_with`, which `SensitiveType` cannot cover.
+///
+/// - `#[sensitive(scalar)]`: Redacts the field like a scalar without inspecting its type. Bare
+///   `#[sensitive]` recognizes primitive names and their `std::primitive`/`core::primitive` paths,
+///   but not type aliases such as `type Cents = i64`; mark those `scalar`. The derive cannot see
+///   through an alias and reports nothing: a bare `#[sensitive]` on one compiles and leaves the
+///   value unchanged.
+///
+/// - `#[sensitive(public)]`: The field passes through unchanged, even when the container sets a
+///   `default` classification.
 ///
 /// - `#[sensitive(Classification)]`: Treats the field as a sensitive string-like value and applies
 ///   the classification's policy. Works for `String`, `Option<String>`, `Vec<String>`, `Box<String>`.
 ///   The type must implement `SensitiveValue`.
 ///
-/// - `#[sensitive]` on `Box<dyn Trait>`: The derive detects the specific syntax
-///   `Box<dyn Trait>` and calls `redaction::redact_boxed`. This only matches the
-///   unqualified form (not `std::boxed::Box<dyn Trait>` or aliases). The trait
-///   object must implement `RedactableBoxed`.
+/// - `#[sensitive(with = path)]`: Calls `path(value)` to redact the field. The function takes and
+///   returns the field type, so this works for external types such as `url::Url` or `Vec<u8>`
+///   that neither implement `SensitiveValue` nor derive `Sensitive`. The function does not receive
+///   the active mapper, so anything it redacts internally ignores registry overrides and
+///   tokenization.
+///
+/// - `#[sensitive(replace = expr)]`: Replaces the field with `expr`, evaluated with the field type
+///   as its expected type (e.g. `None`, `Uuid::nil()`, `Default::default()`).
+///
+/// - `#[sensitive(drop)]`: Replaces an `Option<T>` field with `None`. Other field types are a
+///   compile error. Add `#[serde(skip_serializing_if = "Option::is_none")]` to omit the field
+///   from serialized output.
+///
+/// - `#[sensitive]` on `Box<dyn Trait>`, `Arc<dyn Trait>`, or `Rc<dyn Trait>`: The derive
+///   detects these forms, bare or through `std`/`alloc` paths (e.g. `std::boxed::Box<dyn Trait>`),
+///   and redacts through `redaction::RedactablePointer`. `dyn Trait` must implement
+///   `RedactableBoxed` for `Box`, or `RedactableShared` for `Arc`/`Rc`.
+///
+/// - `#[sensitive(boxed)]`: Redacts the field like a trait object pointer without inspecting its
+///   type, for aliases such as `type Handler = Box<dyn Redactor>`.
 ///
 /// Unions are rejected at compile time.
 ///
@@ -248,19 +293,23 @@ fn expand(input: DeriveInput, slog_mode: SlogMode) -> Result<TokenStream> {
         ..
     } = input;
 
-    let ContainerOptions { skip_debug } = parse_container_options(&attrs)?;
+    // Borrowed so each impl below clones its own copy, whichever features are enabled
+    let generics = &generics;
+    let options = parse_container_options(&attrs)?;
 
     let crate_root = crate_root();
 
     let redacted_display_output = if matches!(slog_mode, SlogMode::RedactedDisplayString) {
-        Some(derive_redacted_display(&ident, &data, &attrs, &generics)?)
+        Some(derive_redacted_display(
+            &ident, &data, &attrs, generics, &options,
+        )?)
     } else {
         None
     };
 
     let derive_output = match &data {
         Data::Struct(data) => {
-            let output = derive_struct(&ident, data.clone(), &generics)?;
+            let output = derive_struct(&ident, data.clone(), generics, &options)?;
             DeriveOutput {
                 redaction_body: output.redaction_body,
                 used_generics: output.used_generics,
@@ -291,7 +340,7 @@ fn expand(input: DeriveInput, slog_mode: SlogMode) -> Result<TokenStream> {
             }
         }
         Data::Enum(data) => {
-            let output = derive_enum(&ident, data.clone(), &generics)?;
+            let output = derive_enum(&ident, data.clone(), generics, &options)?;
             DeriveOutput {
                 redaction_body: output.redaction_body,
                 used_generics: output.used_generics,
@@ -347,7 +396,7 @@ fn expand(input: DeriveInput, slog_mode: SlogMode) -> Result<TokenStream> {
     let redaction_body = &derive_output.redaction_body;
     let debug_redacted_body = &derive_output.debug_redacted_body;
     let debug_unredacted_body = &derive_output.debug_unredacted_body;
-    let debug_impl = if skip_debug {
+    let debug_impl = if options.skip_debug {
         quote! {}
     } else {
         quote! {
@@ -405,7 +454,7 @@ fn expand(input: DeriveInput, slog_mode: SlogMode) -> Result<TokenStream> {
     #[cfg(feature = "slog")]
     let slog_impl = {
         let slog_crate = slog_crate()?;
-        let mut slog_generics = generics;
+        let mut slog_generics = generics.clone();
         let slog_where_clause = slog_generics.make_where_clause();
         let self_ty: syn::Type = parse_quote!(#ident #ty_generics);
         match slog_mode {
@@ -463,6 +512,8 @@ fn expand(input: DeriveInput, slog_mode: SlogMode) -> Result<TokenStream> {
     #[cfg(not(feature = "slog"))]
     let slog_impl = quote! {};
 
+    let name_warnings = check_field_names(&data, &options)?;
+
     let trait_impl = quote! {
         #[allow(unused_assignments)]
         impl #impl_generics #crate_root::SensitiveType for #ident #ty_generics #where_clause {
@@ -478,6 +529,8 @@ fn expand(input: DeriveInput, slog_mode: SlogMode) -> Result<TokenStream> {
 
         #slog_impl
 
+        #name_warnings
+
         // `slog` already provides `impl<V: Value> Value for &V`, so a reference
         // impl here would conflict with the blanket impl.
     };
@@ -10,9 +10,10 @@ use quote::{format_ident, quote, quote_spanned};
 use syn::{spanned::Spanned, Attribute, Data, DataEnum, DataStruct, Fields, LitStr, Result};
 
 use crate::{
+    container::ContainerOptions,
     crate_path,
     generics::collect_generics_from_type,
-    strategy::{parse_field_strategy, Strategy},
+    strategy::{resolve_field_strategy, Strategy},
     types::is_scalar_type,
 };
 
@@ -64,10 +65,11 @@ pub(crate) fn derive_redacted_display(
     data: &Data,
     attrs: &[Attribute],
     generics: &syn::Generics,
+    options: &ContainerOptions,
 ) -> Result<RedactedDisplayOutput> {
     match data {
-        Data::Struct(data) => derive_struct_display(name, data, attrs, generics),
-        Data::Enum(data) => derive_enum_display(name, data, generics),
+        Data::Struct(data) => derive_struct_display(name, data, attrs, generics, options),
+        Data::Enum(data) => derive_enum_display(name, data, generics, options),
         Data::Union(u) => Err(syn::Error::new(
             u.union_token.span(),
             "`SensitiveError` cannot be derived for unions",
@@ -80,9 +82,10 @@ fn derive_struct_display(
     data: &DataStruct,
     attrs: &[Attribute],
     generics: &syn::Generics,
+    options: &ContainerOptions,
 ) -> Result<RedactedDisplayOutput> {
     let template = template_from_attrs(attrs, name.span())?;
-    let fields = build_fields(data)?;
+    let fields = build_fields(data, options)?;
     let format_args = build_format_args(&template, &fields, generics)?;
     let format_prelude = format_args.prelude.clone();
     let bindings = fields.iter().map(|field| field.ident.clone());
@@ -112,6 +115,7 @@ fn derive_enum_display(
     name: &Ident,
     data: &DataEnum,
     generics: &syn::Generics,
+    options: &ContainerOptions,
 ) -> Result<RedactedDisplayOutput> {
     let mut arms = Vec::new();
     let mut display_generics = Vec::new();
@@ -121,7 +125,7 @@ fn derive_enum_display(
 
     for variant in &data.variants {
         let template = template_from_attrs(&variant.attrs, variant.ident.span())?;
-        let fields = build_fields_from_variant(variant)?;
+        let fields = build_fields_from_variant(variant, options)?;
         let format_args = build_format_args(&template, &fields, generics)?;
         let format_prelude = format_args.prelude.clone();
         let bindings = fields.iter().map(|field| field.ident.clone());
@@ -159,13 +163,16 @@ fn derive_enum_display(
     })
 }
 
-fn build_fields(data: &DataStruct) -> Result<Vec<FieldInfo<'_>>> {
+fn build_fields<'a>(
+    data: &'a DataStruct,
+    options: &ContainerOptions,
+) -> Result<Vec<FieldInfo<'a>>> {
     match &data.fields {
         Fields::Named(fields) => fields
             .named
             .iter()
             .map(|field| {
-                let strategy = parse_field_strategy(&field.attrs)?;
+                let strategy = resolve_field_strategy(field, options)?;
                 let ident = field
                     .ident
                     .clone()
@@ -183,7 +190,7 @@ fn build_fields(data: &DataStruct) -> Result<Vec<FieldInfo<'_>>> {
             .iter()
             .enumerate()
             .map(|(index, field)| {
-                let strategy = parse_field_strategy(&field.attrs)?;
+                let strategy = resolve_field_strategy(field, options)?;
                 Ok(FieldInfo {
                     ident: format_ident!("field_{index}"),
                     ty: &field.ty,
@@ -196,13 +203,16 @@ fn build_fields(data: &DataStruct) -> Result<Vec<FieldInfo<'_>>> {
     }
 }
 
-fn build_fields_from_variant(variant: &syn::Variant) -> Result<Vec<FieldInfo<'_>>> {
+fn build_fields_from_variant<'a>(
+    variant: &'a syn::Variant,
+    options: &ContainerOptions,
+) -> Result<Vec<FieldInfo<'a>>> {
     match &variant.fields {
         Fields::Named(fields) => fields
             .named
             .iter()
             .map(|field| {
-                let strategy = parse_field_strategy(&field.attrs)?;
+                let strategy = resolve_field_strategy(field, options)?;
                 let ident = field
                     .ident
                     .clone()
@@ -220,7 +230,7 @@ fn build_fields_from_variant(variant: &syn::Variant) -> Result<Vec<FieldInfo<'_>
             .iter()
             .enumerate()
             .map(|(index, field)| {
-                let strategy = parse_field_strategy(&field.attrs)?;
+                let strategy = resolve_field_strategy(field, options)?;
                 Ok(FieldInfo {
                     ident: format_ident!("field_{index}"),
                     ty: &field.ty,
@@ -357,7 +367,7 @@ fn redacted_expr_for_field(field: &FieldInfo<'_>) -> TokenStream {
         Strategy::PassThrough => quote_spanned! { span =>
             #ident
         },
-        Strategy::Walk => {
+        Strategy::Walk | Strategy::Boxed => {
             if is_scalar_type(field.ty) {
                 quote_spanned! { span =>
                     #scalar_path::redact(*#ident)
@@ -368,12 +378,36 @@ fn redacted_expr_for_field(field: &FieldInfo<'_>) -> TokenStream {
                 }
             }
         }
+        Strategy::Scalar => quote_spanned! { span =>
+            #scalar_path::redact(*#ident)
+        },
         Strategy::Classify(classification) => {
             let classification = classification.clone();
             quote_spanned! { span =>
                 #apply_classification_path::<#classification, _>((*#ident).clone())
             }
         }
+        Strategy::With(function) => quote_spanned! { span =>
+            #function((*#ident).clone())
+        },
+        Strategy::Replace(replacement) => {
+            let ty = field.ty;
+            quote_spanned! { span =>
+                {
+                    let replacement: #ty = #replacement;
+                    replacement
+                }
+            }
+        }
+        Strategy::Drop => {
+            let ty = field.ty;
+            quote_spanned! { span =>
+                {
+                    let dropped: #ty = ::core::option::Option::None;
+                    dropped
+                }
+            }
+        }
     }
 }
 
@@ -387,7 +421,7 @@ fn collect_bounds(
     nested_generics: &mut Vec<Ident>,
 ) {
     match &field.strategy {
-        Strategy::PassThrough => match mode {
+        Strategy::PassThrough | Strategy::Replace(_) | Strategy::Drop => match mode {
             FormatMode::Display => collect_generics_from_type(field.ty, generics, display_generics),
             FormatMode::Debug => collect_generics_from_type(field.ty, generics, debug_generics),
             FormatMode::Both => {
@@ -395,12 +429,13 @@ fn collect_bounds(
                 collect_generics_from_type(field.ty, generics, debug_generics);
             }
         },
-        Strategy::Walk => {
+        Strategy::Walk | Strategy::Boxed => {
             if !is_scalar_type(field.ty) {
                 collect_generics_from_type(field.ty, generics, nested_generics);
             }
         }
-        Strategy::Classify(_) => {
+        Strategy::Scalar => {}
+        Strategy::Classify(_) | Strategy::With(_) => {
             collect_generics_from_type(field.ty, generics, clone_generics);
             match mode {
                 FormatMode::Display => {
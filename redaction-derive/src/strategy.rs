@@ -4,7 +4,13 @@
 //! structured errors for invalid forms.
 
 use proc_macro2::Span;
-use syn::{spanned::Spanned, Attribute, Meta, Result};
+use syn::{spanned::Spanned, Attribute, Meta, MetaList, Result};
+
+use crate::{
+    container::ContainerOptions,
+    crate_path,
+    types::{is_option_type, is_string_like_type},
+};
 
 /// Field transformation strategy based on `#[sensitive(...)]` attributes.
 ///
@@ -13,8 +19,17 @@ use syn::{spanned::Spanned, Attribute, Meta, Result};
 /// | Attribute | Strategy | Behavior |
 /// |-----------|----------|----------|
 /// | None | `PassThrough` | Field passes through unchanged |
+/// | None, with container `default = Class` | `Classify(Class)` | String-like fields only |
+/// | `#[sensitive(public)]` | `PassThrough` | Explicit opt-out of the container default |
+/// | None, with container `deny_unannotated` | error | Unless covered by `default = Class` |
 /// | `#[sensitive]` | `Walk` | Walk containers OR redact scalars |
+/// | `#[sensitive(scalar)]` | `Scalar` | Redact an aliased scalar to its default |
+/// | `#[sensitive(boxed)]` | `Boxed` | Redact an aliased `Box`/`Arc`/`Rc` trait object |
 /// | `#[sensitive(Class)]` | `Classify(Class)` | Apply classification policy |
+/// | `#[sensitive(scan)]` | `Classify(FreeText)` | Redact values detected in free text |
+/// | `#[sensitive(with = path)]` | `With(path)` | Call `path(value)` to redact the field |
+/// | `#[sensitive(replace = expr)]` | `Replace(expr)` | Substitute `expr` for the field |
+/// | `#[sensitive(drop)]` | `Drop` | `Option<T>` fields only: become `None` |
 #[derive(Clone, Debug)]
 pub(crate) enum Strategy {
     /// No annotation: pass through unchanged.
@@ -27,11 +42,43 @@ pub(crate) enum Strategy {
     /// - For scalars (i32, bool, etc.): redact to default value
     /// - For structs: walk using `SensitiveContainer::redact_with`
     Walk,
+    /// `#[sensitive(scalar)]`: redact to the scalar default without type detection.
+    ///
+    /// For aliases such as `type Cents = i64`, which bare `#[sensitive]` cannot
+    /// recognize as scalars. Bare `#[sensitive]` on such an alias walks it as a
+    /// `SensitiveType`, which leaves the value unchanged without any diagnostic.
+    Scalar,
+    /// `#[sensitive(boxed)]`: redact a trait object pointer without type detection.
+    ///
+    /// For aliases such as `type Handler = Box<dyn Redactor>`, which bare
+    /// `#[sensitive]` cannot recognize as trait object pointers.
+    Boxed,
     /// `#[sensitive(Classification)]`: apply classification policy.
     ///
     /// The classification type (e.g., `Secret`, `Pii`) determines how
     /// the value is redacted via `RedactionPolicy`.
     Classify(syn::Path),
+    /// `#[sensitive(with = path)]`: call a user function `fn(T) -> T`.
+    ///
+    /// For field types that neither implement `SensitiveValue` nor derive
+    /// `Sensitive` (e.g. `url::Url`, `Vec<u8>`).
+    With(syn::ExprPath),
+    /// `#[sensitive(replace = expr)]`: substitute a fixed value of the field type.
+    ///
+    /// For sentinels such as `None`, `Uuid::nil()`, or `Default::default()`.
+    Replace(syn::Expr),
+    /// `#[sensitive(drop)]`: replace an `Option<T>` field with `None`.
+    ///
+    /// Paired with serde's `skip_serializing_if = "Option::is_none"`, the
+    /// field is omitted from serialized output entirely.
+    Drop,
+}
+
+impl Strategy {
+    /// Returns `true` if the field's value is hidden in redacted output.
+    pub(crate) fn is_sensitive(&self) -> bool {
+        !matches!(self, Strategy::PassThrough)
+    }
 }
 
 fn set_strategy(target: &mut Option<Strategy>, next: Strategy, span: Span) -> Result<()> {
@@ -45,7 +92,11 @@ fn set_strategy(target: &mut Option<Strategy>, next: Strategy, span: Span) -> Re
     Ok(())
 }
 
-pub(crate) fn parse_field_strategy(attrs: &[Attribute]) -> Result<Strategy> {
+/// Parses a field's `#[sensitive(...)]` attributes.
+///
+/// Returns `None` if the field carries no `#[sensitive]` attribute at all, so
+/// callers can tell unannotated fields from explicit `#[sensitive(public)]`.
+pub(crate) fn parse_field_strategy(attrs: &[Attribute]) -> Result<Option<Strategy>> {
     let mut strategy: Option<Strategy> = None;
     for attr in attrs {
         if !attr.path().is_ident("sensitive") {
@@ -58,18 +109,7 @@ pub(crate) fn parse_field_strategy(attrs: &[Attribute]) -> Result<Strategy> {
                 set_strategy(&mut strategy, Strategy::Walk, attr.span())?;
             }
             Meta::List(list) => {
-                // Parse as a classification path (e.g., #[sensitive(Secret)])
-                match syn::parse2::<syn::Path>(list.tokens.clone()) {
-                    Ok(path) => {
-                        set_strategy(&mut strategy, Strategy::Classify(path), attr.span())?;
-                    }
-                    Err(_) => {
-                        return Err(syn::Error::new(
-                            attr.span(),
-                            "expected a classification type (e.g., #[sensitive(Secret)])",
-                        ));
-                    }
-                }
+                set_strategy(&mut strategy, parse_list_strategy(list)?, attr.span())?;
             }
             Meta::NameValue(_) => {
                 return Err(syn::Error::new(
@@ -80,8 +120,118 @@ pub(crate) fn parse_field_strategy(attrs: &[Attribute]) -> Result<Strategy> {
         }
     }
 
-    // Default: no annotation means pass through unchanged
-    Ok(strategy.unwrap_or(Strategy::PassThrough))
+    Ok(strategy)
+}
+
+/// Parses the contents of `#[sensitive(...)]`.
+///
+/// A bare path is a keyword (`public`, `scalar`, `boxed`, `scan`, `drop`) or a
+/// classification type; a `name = value` pair is a field option (`with = path`,
+/// `replace = expr`).
+fn parse_list_strategy(list: &MetaList) -> Result<Strategy> {
+    if let Ok(path) = syn::parse2::<syn::Path>(list.tokens.clone()) {
+        if path.is_ident("public") {
+            // #[sensitive(public)] opts out of a container default
+            return Ok(Strategy::PassThrough);
+        }
+        if path.is_ident("scalar") {
+            return Ok(Strategy::Scalar);
+        }
+        if path.is_ident("boxed") {
+            return Ok(Strategy::Boxed);
+        }
+        if path.is_ident("drop") {
+            return Ok(Strategy::Drop);
+        }
+        return Ok(Strategy::Classify(classification_path(path)?));
+    }
+
+    let Ok(option) = syn::parse2::<syn::MetaNameValue>(list.tokens.clone()) else {
+        return Err(syn::Error::new(
+            list.span(),
+            "expected a classification type (e.g., #[sensitive(Secret)])",
+        ));
+    };
+    if option.path.is_ident("replace") {
+        return Ok(Strategy::Replace(option.value));
+    }
+    if option.path.is_ident("with") {
+        return match option.value {
+            syn::Expr::Path(function) => Ok(Strategy::With(function)),
+            other => Err(syn::Error::new(
+                other.span(),
+                "expected a function path (e.g., #[sensitive(with = my_mod::redact)])",
+            )),
+        };
+    }
+    Err(syn::Error::new(
+        option.path.span(),
+        format!(
+            "unknown #[sensitive] option `{}`; expected `with` or `replace`",
+            option
+                .path
+                .get_ident()
+                .map_or_else(|| "?".to_string(), ToString::to_string)
+        ),
+    ))
+}
+
+/// Resolves a classification path, expanding the `scan` shorthand.
+pub(crate) fn classification_path(path: syn::Path) -> Result<syn::Path> {
+    if path.is_ident("scan") {
+        // `scan` is shorthand for the FreeText classification, which only
+        // exists with the scanner feature
+        if cfg!(feature = "scanner") {
+            syn::parse2(crate_path("FreeText"))
+        } else {
+            Err(syn::Error::new(
+                path.span(),
+                "`#[sensitive(scan)]` requires the `scanner` feature of `redaction`",
+            ))
+        }
+    } else {
+        Ok(path)
+    }
+}
+
+/// Determines how a field is transformed, applying container-level defaults.
+///
+/// Field annotations always win. Unannotated string-like fields take the
+/// container's `default` classification; everything else passes through, or
+/// is rejected under `deny_unannotated`.
+pub(crate) fn resolve_field_strategy(
+    field: &syn::Field,
+    options: &ContainerOptions,
+) -> Result<Strategy> {
+    if let Some(strategy) = parse_field_strategy(&field.attrs)? {
+        if matches!(strategy, Strategy::Drop) && !is_option_type(&field.ty) {
+            return Err(syn::Error::new(
+                field.ty.span(),
+                "#[sensitive(drop)] requires an `Option<T>` field; \
+                use #[sensitive(replace = expr)] for other types",
+            ));
+        }
+        return Ok(strategy);
+    }
+    match &options.default_classification {
+        Some(classification) if is_string_like_type(&field.ty) => {
+            Ok(Strategy::Classify(classification.clone()))
+        }
+        _ if options.deny_unannotated => {
+            let name = field
+                .ident
+                .as_ref()
+                .map_or_else(|| "this field".to_string(), |ident| format!("`{ident}`"));
+            Err(syn::Error::new(
+                field.span(),
+                format!(
+                    "{name} has no #[sensitive] annotation, which `deny_unannotated` forbids: \
+                    add #[sensitive(Classification)], #[sensitive], or #[sensitive(public)]"
+                ),
+            ))
+        }
+        _ => Ok(Strategy::PassThrough),
+    }
 }
 
 #[cfg(test)]
@@ -100,18 +250,135 @@ mod tests {
         input.attrs
     }
 
+    fn parse_field(tokens: proc_macro2::TokenStream) -> syn::Field {
+        let input: DeriveInput = syn::parse2(quote! {
+            struct Dummy { #tokens }
+        })
+        .expect("should parse as DeriveInput");
+        match input.data {
+            syn::Data::Struct(data) => data.fields.into_iter().next().expect("one field"),
+            _ => unreachable!(),
+        }
+    }
+
+    fn options_with_default(tokens: proc_macro2::TokenStream) -> ContainerOptions {
+        ContainerOptions {
+            default_classification: Some(syn::parse2(tokens).expect("should parse as Path")),
+            ..ContainerOptions::default()
+        }
+    }
+
     #[test]
-    fn no_attribute_returns_passthrough() {
+    fn no_attribute_returns_none() {
         let attrs = parse_attrs(quote! {});
         let strategy = parse_field_strategy(&attrs).unwrap();
-        assert!(matches!(strategy, Strategy::PassThrough));
+        assert!(strategy.is_none());
     }
 
     #[test]
     fn bare_sensitive_returns_walk() {
         let attrs = parse_attrs(quote! { #[sensitive] });
         let strategy = parse_field_strategy(&attrs).unwrap();
-        assert!(matches!(strategy, Strategy::Walk));
+        assert!(matches!(strategy, Some(Strategy::Walk)));
+    }
+
+    #[test]
+    fn public_returns_passthrough() {
+        let attrs = parse_attrs(quote! { #[sensitive(public)] });
+        let strategy = parse_field_strategy(&attrs).unwrap();
+        assert!(matches!(strategy, Some(Strategy::PassThrough)));
+    }
+
+    #[test]
+    fn drop_requires_option_field() {
+        let scan = parse_field(quote! { #[sensitive(drop)] scan: Option<Vec<u8>> });
+        assert!(matches!(
+            resolve_field_strategy(&scan, &ContainerOptions::default()).unwrap(),
+            Strategy::Drop
+        ));
+        let blob = parse_field(quote! { #[sensitive(drop)] blob: Vec<u8> });
+        let err = resolve_field_strategy(&blob, &ContainerOptions::default()).unwrap_err();
+        assert!(err.to_string().contains("requires an `Option<T>` field"));
+    }
+
+    #[test]
+    fn unannotated_field_without_default_passes_through() {
+        let field = parse_field(quote! { name: String });
+        let strategy = resolve_field_strategy(&field, &ContainerOptions::default()).unwrap();
+        assert!(matches!(strategy, Strategy::PassThrough));
+    }
+
+    #[test]
+    fn container_default_classifies_string_like_fields() {
+        let options = options_with_default(quote! { Pii });
+        for field in [
+            parse_field(quote! { name: String }),
+            parse_field(quote! { aliases: Option<Vec<String>> }),
+        ] {
+            match resolve_field_strategy(&field, &options).unwrap() {
+                Strategy::Classify(path) => assert!(path.is_ident("Pii")),
+                other => panic!("expected Classify, got {other:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn deny_unannotated_rejects_implicit_pass_through() {
+        let options = ContainerOptions {
+            deny_unannotated: true,
+            ..ContainerOptions::default()
+        };
+        let ssn = parse_field(quote! { ssn: String });
+        let err = resolve_field_strategy(&ssn, &options).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("`ssn` has no #[sensitive] annotation"));
+
+        let public = parse_field(quote! { #[sensitive(public)] status: String });
+        assert!(matches!(
+            resolve_field_strategy(&public, &options).unwrap(),
+            Strategy::PassThrough
+        ));
+        let walked = parse_field(quote! { #[sensitive] age: u32 });
+        assert!(matches!(
+            resolve_field_strategy(&walked, &options).unwrap(),
+            Strategy::Walk
+        ));
+    }
+
+    #[test]
+    fn deny_unannotated_accepts_container_default() {
+        let options = ContainerOptions {
+            deny_unannotated: true,
+            ..options_with_default(quote! { Pii })
+        };
+        let name = parse_field(quote! { name: String });
+        assert!(matches!(
+            resolve_field_strategy(&name, &options).unwrap(),
+            Strategy::Classify(_)
+        ));
+        let age = parse_field(quote! { age: u32 });
+        assert!(resolve_field_strategy(&age, &options).is_err());
+    }
+
+    #[test]
+    fn container_default_skips_other_types_and_annotated_fields() {
+        let options = options_with_default(quote! { Pii });
+        let age = parse_field(quote! { age: u32 });
+        assert!(matches!(
+            resolve_field_strategy(&age, &options).unwrap(),
+            Strategy::PassThrough
+        ));
+        let public = parse_field(quote! { #[sensitive(public)] status: String });
+        assert!(matches!(
+            resolve_field_strategy(&public, &options).unwrap(),
+            Strategy::PassThrough
+        ));
+        let secret = parse_field(quote! { #[sensitive(Secret)] password: String });
+        match resolve_field_strategy(&secret, &options).unwrap() {
+            Strategy::Classify(path) => assert!(path.is_ident("Secret")),
+            other => panic!("expected Classify, got {other:?}"),
+        }
     }
 
     #[test]
@@ -119,7 +386,7 @@ mod tests {
         let attrs = parse_attrs(quote! { #[sensitive(Secret)] });
         let strategy = parse_field_strategy(&attrs).unwrap();
         match strategy {
-            Strategy::Classify(path) => {
+            Some(Strategy::Classify(path)) => {
                 assert!(path.is_ident("Secret"));
             }
             _ => panic!("expected Classify"),
@@ -131,13 +398,92 @@ mod tests {
         let attrs = parse_attrs(quote! { #[sensitive(my_module::MyClassification)] });
         let strategy = parse_field_strategy(&attrs).unwrap();
         match strategy {
-            Strategy::Classify(path) => {
+            Some(Strategy::Classify(path)) => {
                 assert_eq!(path.segments.len(), 2);
             }
             _ => panic!("expected Classify"),
         }
     }
 
+    #[test]
+    #[cfg(feature = "scanner")]
+    fn sensitive_scan_classifies_as_free_text() {
+        let attrs = parse_attrs(quote! { #[sensitive(scan)] });
+        let strategy = parse_field_strategy(&attrs).unwrap();
+        match strategy {
+            Some(Strategy::Classify(path)) => {
+                let last = path.segments.last().unwrap();
+                assert_eq!(last.ident, "FreeText");
+            }
+            _ => panic!("expected Classify"),
+        }
+    }
+
+    #[test]
+    #[cfg(not(feature = "scanner"))]
+    fn sensitive_scan_requires_scanner_feature() {
+        let attrs = parse_attrs(quote! { #[sensitive(scan)] });
+        let error = parse_field_strategy(&attrs).unwrap_err();
+        assert!(error.to_string().contains("`scanner` feature"));
+    }
+
+    #[test]
+    fn sensitive_with_returns_function_path() {
+        let attrs = parse_attrs(quote! { #[sensitive(with = my_mod::redact_iban)] });
+        match parse_field_strategy(&attrs).unwrap() {
+            Some(Strategy::With(function)) => assert_eq!(function.path.segments.len(), 2),
+            other => panic!("expected With, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn sensitive_with_requires_path() {
+        let attrs = parse_attrs(quote! { #[sensitive(with = "redact")] });
+        let result = parse_field_strategy(&attrs);
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("expected a function path"));
+    }
+
+    #[test]
+    fn scalar_returns_scalar() {
+        let attrs = parse_attrs(quote! { #[sensitive(scalar)] });
+        let strategy = parse_field_strategy(&attrs).unwrap();
+        assert!(matches!(strategy, Some(Strategy::Scalar)));
+    }
+
+    #[test]
+    fn boxed_returns_boxed() {
+        let attrs = parse_attrs(quote! { #[sensitive(boxed)] });
+        let strategy = parse_field_strategy(&attrs).unwrap();
+        assert!(matches!(strategy, Some(Strategy::Boxed)));
+    }
+
+    #[test]
+    fn sensitive_replace_returns_expression() {
+        let attrs = parse_attrs(quote! { #[sensitive(replace = Uuid::nil())] });
+        assert!(matches!(
+            parse_field_strategy(&attrs).unwrap(),
+            Some(Strategy::Replace(syn::Expr::Call(_)))
+        ));
+        let attrs = parse_attrs(quote! { #[sensitive(replace = None)] });
+        assert!(matches!(
+            parse_field_strategy(&attrs).unwrap(),
+            Some(Strategy::Replace(syn::Expr::Path(_)))
+        ));
+    }
+
+    #[test]
+    fn unknown_name_value_option_error() {
+        let attrs = parse_attrs(quote! { #[sensitive(using = redact)] });
+        let result = parse_field_strategy(&attrs);
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("unknown #[sensitive] option `using`"));
+    }
+
     #[test]
     fn multiple_sensitive_attributes_error() {
         let attrs = parse_attrs(quote! {
@@ -181,6 +527,6 @@ mod tests {
             #[serde(skip)]
         });
         let strategy = parse_field_strategy(&attrs).unwrap();
-        assert!(matches!(strategy, Strategy::PassThrough));
+        assert!(strategy.is_none());
     }
 }
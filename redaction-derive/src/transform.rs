@@ -8,10 +8,11 @@ use quote::quote_spanned;
 use syn::Result;
 
 use crate::{
+    container::ContainerOptions,
     crate_path,
     generics::collect_generics_from_type,
     strategy::Strategy,
-    types::{is_boxed_dyn_type, is_scalar_type},
+    types::{arc_lock_type, is_dyn_pointer_type, is_scalar_type, ArcLock},
 };
 
 /// Accumulated state during field processing.
@@ -20,6 +21,7 @@ use crate::{
 /// during traversal of struct fields or enum variants.
 pub(crate) struct DeriveContext<'a> {
     pub(crate) generics: &'a syn::Generics,
+    pub(crate) options: &'a ContainerOptions,
     pub(crate) container_path: &'a TokenStream,
     pub(crate) used_generics: &'a mut Vec<Ident>,
     pub(crate) classified_generics: &'a mut Vec<Ident>,
@@ -38,8 +40,13 @@ pub(crate) struct DeriveContext<'a> {
 /// | Annotation | Behavior |
 /// |------------|----------|
 /// | None | Pass through unchanged (identity) |
-/// | `#[sensitive]` | Walk containers OR redact scalars to default |
+/// | `#[sensitive]` | Walk containers, `Arc<Mutex<T>>`/`Arc<RwLock<T>>` OR redact scalars to default |
+/// | `#[sensitive(scalar)]` | Redact to the scalar default |
+/// | `#[sensitive(boxed)]` | Redact a `Box`/`Arc`/`Rc` trait object |
 /// | `#[sensitive(Class)]` | Apply classification recursively through wrappers |
+/// | `#[sensitive(with = path)]` | Call `path(value)` |
+/// | `#[sensitive(replace = expr)]` | Discard the value and use `expr` |
+/// | `#[sensitive(drop)]` | Discard the value and use `None` |
 pub(crate) fn generate_field_transform(
     ctx: &mut DeriveContext<'_>,
     ty: &syn::Type,
@@ -66,10 +73,20 @@ pub(crate) fn generate_field_transform(
                 Ok(quote_spanned! { span =>
                     let #binding = mapper.map_scalar(#binding);
                 })
-            } else if is_boxed_dyn_type(ty) {
-                let redact_boxed_path = crate_path("redact_boxed");
+            } else if is_dyn_pointer_type(ty) {
+                Ok(redact_pointer(binding, span))
+            } else if let Some(lock) = arc_lock_type(ty) {
+                // `Mutex`/`RwLock` are not `Clone`, so the `Arc` impl of
+                // `SensitiveType` cannot cover them; call the helper with the mapper
+                collect_generics_from_type(ty, ctx.generics, ctx.used_generics);
+                collect_generics_from_type(ty, ctx.generics, ctx.debug_redacted_generics);
+                collect_generics_from_type(ty, ctx.generics, ctx.debug_unredacted_generics);
+                let helper = match lock {
+                    ArcLock::Mutex => crate_path("redact_arc_mutex_with"),
+                    ArcLock::RwLock => crate_path("redact_arc_rwlock_with"),
+                };
                 Ok(quote_spanned! { span =>
-                    let #binding = #redact_boxed_path(#binding);
+                    let #binding = #helper(#binding, mapper);
                 })
             } else {
                 // Non-scalars: walk using SensitiveType
@@ -81,6 +98,12 @@ pub(crate) fn generate_field_transform(
                 })
             }
         }
+        // #[sensitive(scalar)]: the user vouches for the type, typically an alias
+        Strategy::Scalar => Ok(quote_spanned! { span =>
+            let #binding = mapper.map_scalar(#binding);
+        }),
+        // #[sensitive(boxed)]: the user vouches for the type, typically an alias
+        Strategy::Boxed => Ok(redact_pointer(binding, span)),
         // #[sensitive(Classification)]: apply classification policy recursively
         // Uses Classifiable trait which handles any nesting depth:
         // String, Option<String>, Vec<String>, Option<Vec<String>>, etc.
@@ -104,5 +127,37 @@ pub(crate) fn generate_field_transform(
                 })
             }
         }
+        // #[sensitive(with = path)]: the function's signature carries the bounds
+        Strategy::With(function) => {
+            collect_generics_from_type(ty, ctx.generics, ctx.debug_unredacted_generics);
+            Ok(quote_spanned! { span =>
+                let #binding = #function(#binding);
+            })
+        }
+        // #[sensitive(replace = expr)]: the annotation lets `None` or
+        // `Default::default()` infer the field type
+        Strategy::Replace(replacement) => {
+            collect_generics_from_type(ty, ctx.generics, ctx.debug_unredacted_generics);
+            Ok(quote_spanned! { span =>
+                let _ = #binding;
+                let #binding: #ty = #replacement;
+            })
+        }
+        // #[sensitive(drop)]: `resolve_field_strategy` has checked for `Option<T>`
+        Strategy::Drop => {
+            collect_generics_from_type(ty, ctx.generics, ctx.debug_unredacted_generics);
+            Ok(quote_spanned! { span =>
+                let _ = #binding;
+                let #binding: #ty = ::core::option::Option::None;
+            })
+        }
+    }
+}
+
+/// Generates the call that redacts a `Box`/`Arc`/`Rc` trait object field.
+fn redact_pointer(binding: &Ident, span: Span) -> TokenStream {
+    let pointer_path = crate_path("RedactablePointer");
+    quote_spanned! { span =>
+        let #binding = #pointer_path::redact_pointer(#binding);
     }
 }
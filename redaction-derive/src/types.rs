@@ -1,82 +1,179 @@
 //! Type utilities for the derive macro.
 
+/// Primitive types that bare `#[sensitive]` redacts to a default value.
+const SCALAR_NAMES: &[&str] = &[
+    "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64", "u128", "usize", "f32",
+    "f64", "bool", "char",
+];
+
 /// Checks if a type is a recognized scalar primitive.
 ///
-/// Returns `true` for bare primitive type names like `i32`, `bool`, `f64`, etc.
-/// Returns `false` for qualified paths, generic types, or type aliases.
+/// Returns `true` for bare primitive type names like `i32`, `bool`, `f64`, and
+/// for the same names through `std::primitive` or `core::primitive` (with or
+/// without a leading `::`). Returns `false` for generic types and type aliases;
+/// those need an explicit `#[sensitive(scalar)]`.
 ///
 /// This is intentionally conservative - if we can't definitively identify
 /// a type as a scalar, we treat it as a potentially sensitive value that
 /// requires a classification.
 pub(crate) fn is_scalar_type(ty: &syn::Type) -> bool {
-    if let syn::Type::Path(path) = ty {
-        if path.path.leading_colon.is_some() {
-            // Absolute path (e.g., ::std::primitive::i32) - not a simple scalar
-            return false;
-        }
-        if path.path.segments.len() != 1 {
-            // Qualified path (e.g., std::primitive::i32) - not a simple scalar
-            return false;
-        }
-        if let Some(segment) = path.path.segments.last() {
-            if !segment.arguments.is_empty() {
-                // Generic type (e.g., Vec<T>) - not a scalar
-                return false;
-            }
-            let ident = &segment.ident;
-            matches!(
-                ident.to_string().as_str(),
-                "i8" | "i16"
-                    | "i32"
-                    | "i64"
-                    | "i128"
-                    | "isize"
-                    | "u8"
-                    | "u16"
-                    | "u32"
-                    | "u64"
-                    | "u128"
-                    | "usize"
-                    | "f32"
-                    | "f64"
-                    | "bool"
-                    | "char"
-            )
-        } else {
-            false
-        }
-    } else {
-        false
+    let syn::Type::Path(path) = ty else {
+        return false;
+    };
+    if path.qself.is_some()
+        || path
+            .path
+            .segments
+            .iter()
+            .any(|segment| !segment.arguments.is_empty())
+    {
+        return false;
     }
+    let segments: Vec<String> = path
+        .path
+        .segments
+        .iter()
+        .map(|segment| segment.ident.to_string())
+        .collect();
+    let name = match segments.as_slice() {
+        // `::i32` would name a crate, not the primitive
+        [name] if path.path.leading_colon.is_none() => name,
+        [root, module, name] if (root == "std" || root == "core") && module == "primitive" => name,
+        _ => return false,
+    };
+    SCALAR_NAMES.contains(&name.as_str())
 }
 
-/// Checks if a type is `Box<dyn Trait>` (including extra bounds on the trait).
-pub(crate) fn is_boxed_dyn_type(ty: &syn::Type) -> bool {
+/// Checks if a type is a string-like leaf, possibly nested in standard wrappers.
+///
+/// Matches `String` and `Cow<str>`, and `Option`, `Vec`, `Box`, sets, and map
+/// values of those (e.g. `Option<Vec<String>>`, `HashMap<K, String>`). Only the
+/// last path segment is inspected, so `std::string::String` also matches; type
+/// aliases do not.
+pub(crate) fn is_string_like_type(ty: &syn::Type) -> bool {
     let syn::Type::Path(path) = ty else {
         return false;
     };
-
-    if path.path.segments.len() != 1 {
+    let Some(segment) = path.path.segments.last() else {
         return false;
+    };
+    let type_args = || -> Vec<&syn::Type> {
+        match &segment.arguments {
+            syn::PathArguments::AngleBracketed(args) => args
+                .args
+                .iter()
+                .filter_map(|arg| match arg {
+                    syn::GenericArgument::Type(ty) => Some(ty),
+                    _ => None,
+                })
+                .collect(),
+            _ => Vec::new(),
+        }
+    };
+    match segment.ident.to_string().as_str() {
+        "String" => segment.arguments.is_empty(),
+        "Cow" => matches!(
+            type_args().as_slice(),
+            [syn::Type::Path(inner)] if inner.path.is_ident("str")
+        ),
+        "Option" | "Vec" | "Box" | "HashSet" | "BTreeSet" => type_args()
+            .first()
+            .is_some_and(|inner| is_string_like_type(inner)),
+        "HashMap" | "BTreeMap" => type_args()
+            .get(1)
+            .is_some_and(|value| is_string_like_type(value)),
+        _ => false,
     }
+}
 
-    let Some(segment) = path.path.segments.first() else {
+/// Checks if a type is `Option<T>`, by its last path segment.
+///
+/// `std::option::Option<T>` also matches; type aliases do not.
+pub(crate) fn is_option_type(ty: &syn::Type) -> bool {
+    let syn::Type::Path(path) = ty else {
         return false;
     };
+    path.path.segments.last().is_some_and(|segment| {
+        segment.ident == "Option"
+            && matches!(segment.arguments, syn::PathArguments::AngleBracketed(_))
+    })
+}
 
-    if segment.ident != "Box" {
-        return false;
+/// Paths that name `Box`, `Arc`, or `Rc`, as `(module path, pointer)`.
+///
+/// The bare name matches anywhere; qualified forms must go through `std` or
+/// `alloc` (e.g. `std::boxed::Box`, `::alloc::sync::Arc`).
+const DYN_POINTERS: &[(&str, &str)] = &[("boxed", "Box"), ("sync", "Arc"), ("rc", "Rc")];
+
+/// Checks if a type is a `Box`, `Arc`, or `Rc` of a trait object.
+///
+/// Matches `Box<dyn Trait>`, `Arc<dyn Trait + Send + Sync>`, `std::rc::Rc<dyn
+/// Trait>` and similar. Type aliases are not resolved; those fields need an
+/// explicit `#[sensitive(boxed)]`.
+pub(crate) fn is_dyn_pointer_type(ty: &syn::Type) -> bool {
+    matches!(
+        std_type_argument(ty, DYN_POINTERS),
+        Some(syn::Type::TraitObject(_))
+    )
+}
+
+/// A lock shared through an `Arc`, which bare `#[sensitive]` redacts with the
+/// matching `redact_arc_*_with` helper.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum ArcLock {
+    /// `Arc<Mutex<T>>`.
+    Mutex,
+    /// `Arc<RwLock<T>>`.
+    RwLock,
+}
+
+/// Checks if a type is an `Arc<Mutex<T>>` or `Arc<RwLock<T>>`.
+///
+/// Both names may be bare or qualified through `std`/`alloc` as for
+/// [`is_dyn_pointer_type`]. Type aliases are not resolved.
+pub(crate) fn arc_lock_type(ty: &syn::Type) -> Option<ArcLock> {
+    let inner = std_type_argument(ty, &[("sync", "Arc")])?;
+    if std_type_argument(inner, &[("sync", "Mutex")]).is_some() {
+        Some(ArcLock::Mutex)
+    } else if std_type_argument(inner, &[("sync", "RwLock")]).is_some() {
+        Some(ArcLock::RwLock)
+    } else {
+        None
     }
+}
 
-    let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
-        return false;
+/// Returns the first type argument of `ty` if it names one of `known`
+/// (`(module, name)` pairs), either bare or as `std::module::Name` /
+/// `alloc::module::Name`.
+fn std_type_argument<'a>(ty: &'a syn::Type, known: &[(&str, &str)]) -> Option<&'a syn::Type> {
+    let syn::Type::Path(path) = ty else {
+        return None;
     };
-
-    let Some(first) = args.args.first() else {
-        return false;
+    if path.qself.is_some() {
+        return None;
+    }
+    let segments: Vec<&syn::PathSegment> = path.path.segments.iter().collect();
+    let (modules, last) = match segments.as_slice() {
+        [last] if path.path.leading_colon.is_none() => (None, *last),
+        [root, module, last] if root.ident == "std" || root.ident == "alloc" => {
+            (Some(*module), *last)
+        }
+        _ => return None,
     };
+    let matches = known.iter().any(|(module, name)| {
+        last.ident == name && modules.is_none_or(|segment| segment.ident == module)
+    });
+    if !matches {
+        return None;
+    }
 
-    matches!(first, syn::GenericArgument::Type(syn::Type::TraitObject(_)))
+    let syn::PathArguments::AngleBracketed(args) = &last.arguments else {
+        return None;
+    };
+    match args.args.first() {
+        Some(syn::GenericArgument::Type(inner)) => Some(inner),
+        _ => None,
+    }
 }
 
 #[cfg(test)]
@@ -120,26 +217,122 @@ mod tests {
     }
 
     #[test]
-    fn qualified_path_is_not_scalar() {
-        let ty = parse_type(quote! { std::primitive::i32 });
-        assert!(!is_scalar_type(&ty));
+    fn qualified_primitive_path_is_scalar() {
+        for ty in [
+            quote! { std::primitive::u64 },
+            quote! { core::primitive::bool },
+            quote! { ::std::primitive::i32 },
+        ] {
+            assert!(is_scalar_type(&parse_type(ty.clone())), "{ty}");
+        }
     }
 
     #[test]
-    fn absolute_path_is_not_scalar() {
-        let ty = parse_type(quote! { ::std::primitive::i32 });
-        assert!(!is_scalar_type(&ty));
+    fn other_qualified_paths_are_not_scalar() {
+        for ty in [
+            quote! { ::i32 },
+            quote! { money::primitive::u64 },
+            quote! { std::primitive::str },
+            quote! { Cents },
+        ] {
+            assert!(!is_scalar_type(&parse_type(ty.clone())), "{ty}");
+        }
+    }
+
+    #[test]
+    fn string_like_types_detected() {
+        for ty in [
+            quote! { String },
+            quote! { std::string::String },
+            quote! { Cow<'static, str> },
+            quote! { Option<Vec<String>> },
+            quote! { HashMap<u64, String> },
+        ] {
+            assert!(is_string_like_type(&parse_type(ty.clone())), "{ty}");
+        }
+    }
+
+    #[test]
+    fn non_string_types_are_not_string_like() {
+        for ty in [
+            quote! { u32 },
+            quote! { Option<u32> },
+            quote! { Vec<Address> },
+            quote! { HashMap<String, u32> },
+            quote! { Cow<'static, [u8]> },
+        ] {
+            assert!(!is_string_like_type(&parse_type(ty.clone())), "{ty}");
+        }
+    }
+
+    #[test]
+    fn option_types_detected() {
+        assert!(is_option_type(&parse_type(quote! { Option<Vec<u8>> })));
+        assert!(is_option_type(&parse_type(
+            quote! { std::option::Option<String> }
+        )));
+        assert!(!is_option_type(&parse_type(quote! { Vec<Option<u8>> })));
+        assert!(!is_option_type(&parse_type(quote! { MaybeBlob })));
     }
 
     #[test]
     fn boxed_dyn_trait_detected() {
         let ty = parse_type(quote! { Box<dyn SomeTrait> });
-        assert!(is_boxed_dyn_type(&ty));
+        assert!(is_dyn_pointer_type(&ty));
     }
 
     #[test]
     fn boxed_type_is_not_dyn_trait() {
         let ty = parse_type(quote! { Box<String> });
-        assert!(!is_boxed_dyn_type(&ty));
+        assert!(!is_dyn_pointer_type(&ty));
+    }
+
+    #[test]
+    fn qualified_and_shared_dyn_pointers_detected() {
+        for ty in [
+            quote! { std::boxed::Box<dyn SomeTrait + Send> },
+            quote! { ::alloc::boxed::Box<dyn SomeTrait> },
+            quote! { Arc<dyn SomeTrait + Send + Sync> },
+            quote! { std::sync::Arc<dyn SomeTrait> },
+            quote! { Rc<dyn SomeTrait> },
+            quote! { alloc::rc::Rc<dyn SomeTrait> },
+        ] {
+            assert!(is_dyn_pointer_type(&parse_type(ty.clone())), "{ty}");
+        }
+    }
+
+    #[test]
+    fn other_pointer_paths_are_not_dyn_pointers() {
+        for ty in [
+            quote! { std::sync::Box<dyn SomeTrait> },
+            quote! { my_crate::boxed::Box<dyn SomeTrait> },
+            quote! { Arc<Config> },
+            quote! { Handler },
+        ] {
+            assert!(!is_dyn_pointer_type(&parse_type(ty.clone())), "{ty}");
+        }
+    }
+
+    #[test]
+    fn arc_locks_detected() {
+        for (ty, lock) in [
+            (quote! { Arc<Mutex<Cache>> }, ArcLock::Mutex),
+            (
+                quote! { std::sync::Arc<std::sync::Mutex<Cache>> },
+                ArcLock::Mutex,
+            ),
+            (quote! { Arc<RwLock<Config>> }, ArcLock::RwLock),
+            (quote! { alloc::sync::Arc<RwLock<Config>> }, ArcLock::RwLock),
+        ] {
+            assert_eq!(arc_lock_type(&parse_type(ty.clone())), Some(lock), "{ty}");
+        }
+        for ty in [
+            quote! { Arc<Config> },
+            quote! { Mutex<Cache> },
+            quote! { Arc<parking_lot::Mutex<Cache>> },
+            quote! { Rc<RefCell<Cache>> },
+        ] {
+            assert_eq!(arc_lock_type(&parse_type(ty.clone())), None, "{ty}");
+        }
     }
 }
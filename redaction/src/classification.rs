@@ -15,64 +15,112 @@
 ///
 /// impl Classification for MyClassification {}
 /// ```
-pub trait Classification {}
+pub trait Classification {
+    /// Returns the name used to refer to this classification at runtime.
+    ///
+    /// Policy registries, tokenizers and configuration files look
+    /// classifications up by this name, so it must be unique within a program.
+    /// Built-in classifications use their short names (e.g. `"Token"`). Custom
+    /// classifications default to their full type path, generics included
+    /// (`"my_app::ids::InternalId"`). That path is unique, but it is not
+    /// guaranteed to stay the same across compiler versions, so do not persist
+    /// it or look it up by a hard-coded string. Override `name` to give a
+    /// classification a stable key; policy files only accept classifications
+    /// that do.
+    fn name() -> &'static str
+    where
+        Self: Sized,
+    {
+        std::any::type_name::<Self>()
+    }
+}
+
+/// Implements [`Classification`] for built-in markers under their short names.
+macro_rules! impl_builtin_classification {
+    ($($ty:ident),* $(,)?) => {
+        $(
+            impl Classification for $ty {
+                fn name() -> &'static str {
+                    stringify!($ty)
+                }
+            }
+        )*
+    };
+}
 
 /// Classification marker for account identifiers.
 #[derive(Clone, Copy)]
 pub struct AccountId;
-impl Classification for AccountId {}
 
 /// Classification marker for blockchain addresses (e.g., Ethereum, Bitcoin).
 #[derive(Clone, Copy)]
 pub struct BlockchainAddress;
-impl Classification for BlockchainAddress {}
 
 /// Classification marker for credit card numbers or PANs.
+///
+/// The default policy keeps the BIN and last 4 digits of Luhn-valid numbers
+/// and fully redacts anything else.
 #[derive(Clone, Copy)]
 pub struct CreditCard;
-impl Classification for CreditCard {}
 
 /// Classification marker for dates of birth.
 #[derive(Clone, Copy)]
 pub struct DateOfBirth;
-impl Classification for DateOfBirth {}
 
 /// Classification marker for email addresses.
 #[derive(Clone, Copy)]
 pub struct Email;
-impl Classification for Email {}
 
 /// Classification marker for IP addresses.
 #[derive(Clone, Copy)]
 pub struct IpAddress;
-impl Classification for IpAddress {}
+
+/// Classification marker for free-form text that may contain embedded sensitive values.
+///
+/// Its policy runs the default [`TextScanner`](crate::TextScanner), replacing
+/// detected values in place and leaving the rest of the text readable. Fields
+/// can also opt in with `#[sensitive(scan)]`.
+#[cfg(feature = "scanner")]
+#[derive(Clone, Copy)]
+pub struct FreeText;
 
 /// Classification marker for government-issued identifiers.
 #[derive(Clone, Copy)]
 pub struct NationalId;
-impl Classification for NationalId {}
 
 /// Classification marker for phone numbers.
 #[derive(Clone, Copy)]
 pub struct PhoneNumber;
-impl Classification for PhoneNumber {}
 
 /// Classification marker for personally identifiable information.
 #[derive(Clone, Copy)]
 pub struct Pii;
-impl Classification for Pii {}
 
 /// Classification marker for secrets such as passwords or private keys.
 #[derive(Clone, Copy)]
 pub struct Secret;
-impl Classification for Secret {}
 
 /// Classification marker for session identifiers.
 #[derive(Clone, Copy)]
 pub struct SessionId;
-impl Classification for SessionId {}
 
 /// Classification marker for authentication tokens and API keys.
 #[derive(Clone, Copy)]
 pub struct Token;
-impl Classification for Token {}
+
+impl_builtin_classification!(
+    AccountId,
+    BlockchainAddress,
+    CreditCard,
+    DateOfBirth,
+    Email,
+    IpAddress,
+    NationalId,
+    PhoneNumber,
+    Pii,
+    Secret,
+    SessionId,
+    Token,
+);
+#[cfg(feature = "scanner")]
+impl_builtin_classification!(FreeText);
@@ -0,0 +1,654 @@
+//! Declarative policy configuration loaded from TOML or JSON.
+//!
+//! This module lets redaction rules live in a policy file owned outside the
+//! codebase. A file maps classification names to policies:
+//!
+//! ```toml
+//! [policies]
+//! Token = { keep_last = 4, mask_char = "#" }
+//! Secret = { full = "<hidden>" }
+//! Email = { keep_first = 2 }
+//! ```
+//!
+//! The equivalent JSON document is
+//! `{"policies": {"Token": {"keep_last": 4, "mask_char": "#"}}}`.
+//!
+//! Loading produces a [`PolicyRegistry`]. Classifications that are not listed
+//! keep their type-level [`RedactionPolicy`](crate::RedactionPolicy).
+//!
+//! ## Policy keys
+//!
+//! | Key | Policy |
+//! |-----|--------|
+//! | `full = true` / `full = "<text>"` | Full redaction (default or custom placeholder) |
+//! | `keep_first`, `keep_last` | Keep segments visible (both may be combined) |
+//! | `mask_first`, `mask_last` | Mask segments (both may be combined) |
+//! | `mask_char` | Masking character for keep/mask/pan policies |
+//! | `truncate_chars`, `truncate_bytes` | Truncation to a scalar-value or byte budget |
+//! | `pan = true` | Card number truncation (first 6 and last 4 digits) |
+//! | `subnet = true` | IP address truncation to the `/24` or `/48` subnet |
+//! | `url = true` | URL userinfo removal and query value redaction |
+//!
+//! An entry selects exactly one policy. Policies that need code or custom
+//! parameters (birth dates, JWTs, FPE, patterns, custom closures, composition
+//! and scanning) cannot be written in a file; set them with
+//! [`PolicyRegistry::with_policy`] instead.
+//!
+//! Validation is strict: unknown classification names, unknown keys, and
+//! contradictory or empty policy entries are rejected with a [`ConfigError`].
+
+use std::{
+    any::TypeId,
+    collections::{BTreeMap, BTreeSet},
+    fmt, fs,
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+
+use crate::{
+    AccountId, BlockchainAddress, Classification, CreditCard, DateOfBirth, Email, IpAddress,
+    KeepConfig, MaskConfig, NationalId, PhoneNumber, Pii, PolicyRegistry, Secret, SessionId,
+    TextRedactionPolicy, Token,
+};
+
+/// Errors produced while loading a policy file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ConfigError {
+    /// The policy file could not be read.
+    Io {
+        /// The path that was being read.
+        path: PathBuf,
+        /// The underlying I/O error message.
+        message: String,
+    },
+    /// The document is not valid TOML/JSON or does not match the expected shape.
+    Parse {
+        /// The document format (`"TOML"` or `"JSON"`).
+        format: &'static str,
+        /// The underlying parser message.
+        message: String,
+    },
+    /// The document names a classification the loader does not know about.
+    UnknownClassification {
+        /// The classification name as written in the document.
+        name: String,
+    },
+    /// Two different classification types registered with the loader share a name.
+    DuplicateClassification {
+        /// The shared [`Classification::name`].
+        name: String,
+    },
+    /// A classification registered with the loader does not override
+    /// [`Classification::name`], so its key would be an unstable type path.
+    UnnamedClassification {
+        /// The type path the classification would have been keyed by.
+        type_name: String,
+    },
+    /// A policy entry is contradictory, empty, or otherwise invalid.
+    InvalidPolicy {
+        /// The classification the entry belongs to.
+        classification: String,
+        /// A description of the problem.
+        reason: String,
+    },
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::Io { path, message } => {
+                write!(
+                    f,
+                    "failed to read policy file {}: {message}",
+                    path.display()
+                )
+            }
+            ConfigError::Parse { format, message } => {
+                write!(f, "failed to parse {format} policy file: {message}")
+            }
+            ConfigError::UnknownClassification { name } => {
+                write!(f, "unknown classification `{name}`")
+            }
+            ConfigError::DuplicateClassification { name } => {
+                write!(f, "more than one classification is named `{name}`")
+            }
+            ConfigError::UnnamedClassification { type_name } => write!(
+                f,
+                "classification `{type_name}` must override `Classification::name` to be used in policy files"
+            ),
+            ConfigError::InvalidPolicy {
+                classification,
+                reason,
+            } => write!(f, "invalid policy for `{classification}`: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+/// Loads policy files into a [`PolicyRegistry`].
+///
+/// The loader knows the built-in classifications. Custom classifications must
+/// be registered with [`PolicyLoader::with_classification`] before a file can
+/// refer to them; anything else is reported as
+/// [`ConfigError::UnknownClassification`]. Files refer to classifications by
+/// [`Classification::name`]. The default name is the type path, which is not
+/// stable across compiler versions, so registered classifications must
+/// override it; otherwise every load fails with
+/// [`ConfigError::UnnamedClassification`]. Registering two types under one
+/// name fails the same way with [`ConfigError::DuplicateClassification`].
+///
+/// ```rust
+/// use redaction::{config::PolicyLoader, Classification};
+///
+/// #[derive(Clone, Copy)]
+/// struct InternalId;
+/// impl Classification for InternalId {
+///     fn name() -> &'static str {
+///         "InternalId"
+///     }
+/// }
+///
+/// let registry = PolicyLoader::new()
+///     .with_classification::<InternalId>()
+///     .load_toml("[policies]\nInternalId = { keep_last = 2 }")
+///     .unwrap();
+/// assert!(registry.get::<InternalId>().is_some());
+/// ```
+#[derive(Clone, Debug)]
+pub struct PolicyLoader {
+    known: BTreeMap<&'static str, TypeId>,
+    duplicates: BTreeSet<&'static str>,
+    unnamed: BTreeSet<&'static str>,
+}
+
+impl Default for PolicyLoader {
+    fn default() -> Self {
+        let loader = Self {
+            known: BTreeMap::new(),
+            duplicates: BTreeSet::new(),
+            unnamed: BTreeSet::new(),
+        }
+        .with_classification::<AccountId>()
+        .with_classification::<BlockchainAddress>()
+        .with_classification::<CreditCard>()
+        .with_classification::<DateOfBirth>()
+        .with_classification::<Email>()
+        .with_classification::<IpAddress>()
+        .with_classification::<NationalId>()
+        .with_classification::<PhoneNumber>()
+        .with_classification::<Pii>()
+        .with_classification::<Secret>()
+        .with_classification::<SessionId>()
+        .with_classification::<Token>();
+        #[cfg(feature = "scanner")]
+        let loader = loader.with_classification::<crate::FreeText>();
+        loader
+    }
+}
+
+impl PolicyLoader {
+    /// Constructs a loader that knows the built-in classifications.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Allows policy files to configure classification `C`.
+    ///
+    /// `C` must override [`Classification::name`]; see [`PolicyLoader`].
+    #[must_use]
+    pub fn with_classification<C>(mut self) -> Self
+    where
+        C: Classification + 'static,
+    {
+        let name = C::name();
+        if name == std::any::type_name::<C>() {
+            self.unnamed.insert(name);
+        }
+        let type_id = TypeId::of::<C>();
+        if *self.known.entry(name).or_insert(type_id) != type_id {
+            self.duplicates.insert(name);
+        }
+        self
+    }
+
+    /// Parses a TOML policy document.
+    pub fn load_toml(&self, text: &str) -> Result<PolicyRegistry, ConfigError> {
+        let file: PolicyFile = toml::from_str(text).map_err(|err| ConfigError::Parse {
+            format: "TOML",
+            message: err.message().to_string(),
+        })?;
+        self.build(file)
+    }
+
+    /// Parses a JSON policy document.
+    pub fn load_json(&self, text: &str) -> Result<PolicyRegistry, ConfigError> {
+        let file: PolicyFile = serde_json::from_str(text).map_err(|err| ConfigError::Parse {
+            format: "JSON",
+            message: err.to_string(),
+        })?;
+        self.build(file)
+    }
+
+    /// Reads and parses a policy file.
+    ///
+    /// Files with a `.json` extension are parsed as JSON; everything else is
+    /// parsed as TOML.
+    pub fn load_file<P>(&self, path: P) -> Result<PolicyRegistry, ConfigError>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        let text = fs::read_to_string(path).map_err(|err| ConfigError::Io {
+            path: path.to_path_buf(),
+            message: err.to_string(),
+        })?;
+        let is_json = path
+            .extension()
+            .is_some_and(|extension| extension.eq_ignore_ascii_case("json"));
+        if is_json {
+            self.load_json(&text)
+        } else {
+            self.load_toml(&text)
+        }
+    }
+
+    fn build(&self, file: PolicyFile) -> Result<PolicyRegistry, ConfigError> {
+        if let Some(name) = self.duplicates.first() {
+            return Err(ConfigError::DuplicateClassification {
+                name: (*name).to_string(),
+            });
+        }
+        if let Some(type_name) = self.unnamed.first() {
+            return Err(ConfigError::UnnamedClassification {
+                type_name: (*type_name).to_string(),
+            });
+        }
+        let mut registry = PolicyRegistry::new();
+        for (name, spec) in file.policies {
+            if !self.known.contains_key(name.as_str()) {
+                return Err(ConfigError::UnknownClassification { name });
+            }
+            let policy = spec
+                .into_policy()
+                .map_err(|reason| ConfigError::InvalidPolicy {
+                    classification: name.clone(),
+                    reason,
+                })?;
+            registry.insert_named(name, policy);
+        }
+        Ok(registry)
+    }
+}
+
+impl PolicyRegistry {
+    /// Parses a TOML policy document using a loader that knows the built-in classifications.
+    ///
+    /// See the [`config`](crate::config) module for the document format.
+    pub fn from_toml_str(text: &str) -> Result<Self, ConfigError> {
+        PolicyLoader::new().load_toml(text)
+    }
+
+    /// Parses a JSON policy document using a loader that knows the built-in classifications.
+    ///
+    /// See the [`config`](crate::config) module for the document format.
+    pub fn from_json_str(text: &str) -> Result<Self, ConfigError> {
+        PolicyLoader::new().load_json(text)
+    }
+
+    /// Reads a TOML or JSON policy file using a loader that knows the built-in
+    /// classifications.
+    ///
+    /// See [`PolicyLoader::load_file`] for how the format is chosen.
+    pub fn from_file<P>(path: P) -> Result<Self, ConfigError>
+    where
+        P: AsRef<Path>,
+    {
+        PolicyLoader::new().load_file(path)
+    }
+}
+
+/// The top-level shape of a policy document.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PolicyFile {
+    #[serde(default)]
+    policies: BTreeMap<String, PolicySpec>,
+}
+
+/// A single policy entry as written in a policy document.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PolicySpec {
+    full: Option<FullSpec>,
+    keep_first: Option<usize>,
+    keep_last: Option<usize>,
+    mask_first: Option<usize>,
+    mask_last: Option<usize>,
+    mask_char: Option<String>,
+    truncate_chars: Option<usize>,
+    truncate_bytes: Option<usize>,
+    pan: Option<bool>,
+    subnet: Option<bool>,
+    url: Option<bool>,
+}
+
+/// `full = true` or `full = "<placeholder>"`.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum FullSpec {
+    Enabled(bool),
+    Placeholder(String),
+}
+
+impl PolicySpec {
+    fn into_policy(self) -> Result<TextRedactionPolicy, String> {
+        let keeps = self.keep_first.is_some() || self.keep_last.is_some();
+        let masks = self.mask_first.is_some() || self.mask_last.is_some();
+        let selected = [
+            self.full.is_some(),
+            keeps,
+            masks,
+            self.truncate_chars.is_some(),
+            self.truncate_bytes.is_some(),
+            self.pan.is_some(),
+            self.subnet.is_some(),
+            self.url.is_some(),
+        ]
+        .into_iter()
+        .filter(|selected| *selected)
+        .count();
+        match selected {
+            0 => return Err(format!("expected one of {POLICY_KEYS}")),
+            1 => {}
+            _ => return Err(format!("{POLICY_KEYS} are mutually exclusive")),
+        }
+
+        let takes_mask_char = keeps || masks || self.pan.is_some();
+        if self.mask_char.is_some() && !takes_mask_char {
+            return Err("`mask_char` only applies to `keep_*`, `mask_*` and `pan`".to_string());
+        }
+
+        let policy = if let Some(full) = self.full {
+            match full {
+                FullSpec::Enabled(false) => {
+                    return Err("`full = false` does not describe a policy".to_string());
+                }
+                FullSpec::Enabled(true) => TextRedactionPolicy::default_full(),
+                FullSpec::Placeholder(placeholder) => TextRedactionPolicy::full_with(placeholder),
+            }
+        } else if keeps {
+            TextRedactionPolicy::keep_with(KeepConfig::both(
+                self.keep_first.unwrap_or(0),
+                self.keep_last.unwrap_or(0),
+            ))
+        } else if masks {
+            TextRedactionPolicy::mask_with(MaskConfig::both(
+                self.mask_first.unwrap_or(0),
+                self.mask_last.unwrap_or(0),
+            ))
+        } else if let Some(max_chars) = self.truncate_chars {
+            TextRedactionPolicy::truncate_chars(max_chars)
+        } else if let Some(max_bytes) = self.truncate_bytes {
+            TextRedactionPolicy::truncate_bytes(max_bytes)
+        } else {
+            let (key, enabled, policy) = if let Some(enabled) = self.pan {
+                ("pan", enabled, TextRedactionPolicy::pan())
+            } else if let Some(enabled) = self.subnet {
+                ("subnet", enabled, TextRedactionPolicy::subnet())
+            } else {
+                (
+                    "url",
+                    self.url.unwrap_or_default(),
+                    TextRedactionPolicy::url(),
+                )
+            };
+            if !enabled {
+                return Err(format!("`{key} = false` does not describe a policy"));
+            }
+            policy
+        };
+
+        match self.mask_char {
+            Some(mask_char) => Ok(policy.with_mask_char(parse_mask_char(&mask_char)?)),
+            None => Ok(policy),
+        }
+    }
+}
+
+/// The policy-selecting keys, as listed in error messages.
+const POLICY_KEYS: &str = "`full`, `keep_*`, `mask_*`, `truncate_*`, `pan`, `subnet` and `url`";
+
+fn parse_mask_char(value: &str) -> Result<char, String> {
+    let mut chars = value.chars();
+    match (chars.next(), chars.next()) {
+        (Some(mask_char), None) => Ok(mask_char),
+        _ => Err(format!(
+            "`mask_char` must be a single character (got {value:?})"
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ConfigError, PolicyLoader};
+    use crate::{
+        Classification, CreditCard, Email, IpAddress, Pii, PolicyRegistry, Secret, Token,
+        REDACTED_PLACEHOLDER,
+    };
+
+    #[test]
+    fn toml_document_builds_registry() {
+        let registry = PolicyRegistry::from_toml_str(
+            r##"
+            [policies]
+            Token = { keep_last = 4, mask_char = "#" }
+            Secret = { full = "<hidden>" }
+            "##,
+        )
+        .unwrap();
+        assert_eq!(registry.len(), 2);
+        assert_eq!(
+            registry.policy_for::<Token>().apply_to("tok_abcdef"),
+            "######cdef"
+        );
+        assert_eq!(
+            registry.policy_for::<Secret>().apply_to("hunter2"),
+            "<hidden>"
+        );
+    }
+
+    #[test]
+    fn json_document_builds_registry() {
+        let registry = PolicyRegistry::from_json_str(
+            r#"{"policies": {"Email": {"keep_first": 1, "keep_last": 4}, "Secret": {"full": true}}}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            registry.policy_for::<Email>().apply_to("alice@x.io"),
+            "a*****x.io"
+        );
+        assert_eq!(
+            registry.policy_for::<Secret>().apply_to("hunter2"),
+            REDACTED_PLACEHOLDER
+        );
+    }
+
+    #[test]
+    fn mask_entries_combine_prefix_and_suffix() {
+        let registry =
+            PolicyRegistry::from_toml_str("[policies]\nToken = { mask_first = 2, mask_last = 1 }")
+                .unwrap();
+        assert_eq!(registry.policy_for::<Token>().apply_to("abcdef"), "**cde*");
+    }
+
+    #[test]
+    fn empty_document_is_an_empty_registry() {
+        assert!(PolicyRegistry::from_toml_str("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn unknown_classification_is_rejected() {
+        let err =
+            PolicyRegistry::from_toml_str("[policies]\nTokn = { keep_last = 4 }").unwrap_err();
+        assert_eq!(
+            err,
+            ConfigError::UnknownClassification {
+                name: "Tokn".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn registered_custom_classification_is_accepted() {
+        #[derive(Clone, Copy)]
+        struct InternalId;
+        impl Classification for InternalId {
+            fn name() -> &'static str {
+                "InternalId"
+            }
+        }
+
+        let text = "[policies]\nInternalId = { keep_last = 2 }";
+        assert!(PolicyRegistry::from_toml_str(text).is_err());
+        let registry = PolicyLoader::new()
+            .with_classification::<InternalId>()
+            .load_toml(text)
+            .unwrap();
+        assert!(registry.get::<InternalId>().is_some());
+    }
+
+    #[test]
+    fn duplicate_classification_names_are_rejected() {
+        mod billing {
+            use crate::Classification;
+
+            #[derive(Clone, Copy)]
+            pub(super) struct Token;
+            impl Classification for Token {
+                fn name() -> &'static str {
+                    "Token"
+                }
+            }
+        }
+
+        let err = PolicyLoader::new()
+            .with_classification::<billing::Token>()
+            .load_toml("[policies]\nSecret = { full = true }")
+            .unwrap_err();
+        assert_eq!(
+            err,
+            ConfigError::DuplicateClassification {
+                name: "Token".to_string()
+            }
+        );
+        // Registering the same type twice is not a conflict
+        assert!(PolicyLoader::new()
+            .with_classification::<Token>()
+            .load_toml("")
+            .is_ok());
+    }
+
+    #[test]
+    fn classifications_without_a_stable_name_are_rejected() {
+        #[derive(Clone, Copy)]
+        struct InternalId;
+        impl Classification for InternalId {}
+
+        let err = PolicyLoader::new()
+            .with_classification::<InternalId>()
+            .load_toml("")
+            .unwrap_err();
+        assert_eq!(
+            err,
+            ConfigError::UnnamedClassification {
+                type_name: InternalId::name().to_string()
+            }
+        );
+        assert!(err
+            .to_string()
+            .contains("must override `Classification::name`"));
+    }
+
+    #[test]
+    fn structured_policies_can_be_selected() {
+        let registry = PolicyRegistry::from_toml_str(
+            r##"
+            [policies]
+            CreditCard = { pan = true, mask_char = "#" }
+            IpAddress = { subnet = true }
+            Pii = { url = true }
+            Token = { truncate_chars = 3 }
+            "##,
+        )
+        .unwrap();
+        assert_eq!(
+            registry
+                .policy_for::<CreditCard>()
+                .apply_to("4111111111111111"),
+            "411111######1111"
+        );
+        assert_eq!(
+            registry.policy_for::<IpAddress>().apply_to("10.1.2.3"),
+            "10.1.2.0"
+        );
+        assert_eq!(
+            registry
+                .policy_for::<Pii>()
+                .apply_to("https://u:p@x.io/a?k=v"),
+            "https://x.io/a?k=[REDACTED]"
+        );
+        assert_eq!(registry.policy_for::<Token>().apply_to("abcdef"), "ab…");
+    }
+
+    #[test]
+    fn custom_classifications_default_to_their_full_path() {
+        #[derive(Clone, Copy)]
+        struct Scoped<T>(T);
+        impl<T> Classification for Scoped<T> {}
+
+        assert_eq!(Token::name(), "Token");
+        let name = Scoped::<u8>::name();
+        assert!(name.starts_with("redaction::config::tests::"), "{name}");
+        assert!(name.ends_with("::Scoped<u8>"), "{name}");
+        assert_ne!(name, Scoped::<u16>::name());
+    }
+
+    #[test]
+    fn invalid_policies_are_rejected() {
+        let cases = [
+            "Token = {}",
+            "Token = { full = true, keep_last = 4 }",
+            "Token = { keep_last = 4, mask_first = 2 }",
+            "Token = { full = false }",
+            "Token = { full = true, mask_char = \"#\" }",
+            "Token = { keep_last = 4, mask_char = \"##\" }",
+            "Token = { pan = true, subnet = true }",
+            "Token = { pan = false }",
+            "Token = { truncate_chars = 4, keep_last = 2 }",
+            "Token = { subnet = true, mask_char = \"#\" }",
+        ];
+        for case in cases {
+            let err = PolicyRegistry::from_toml_str(&format!("[policies]\n{case}")).unwrap_err();
+            assert!(
+                matches!(err, ConfigError::InvalidPolicy { ref classification, .. } if classification == "Token"),
+                "{case}: {err}"
+            );
+        }
+    }
+
+    #[test]
+    fn malformed_documents_are_parse_errors() {
+        let err =
+            PolicyRegistry::from_toml_str("[policies]\nToken = { keep_lst = 4 }").unwrap_err();
+        assert!(matches!(err, ConfigError::Parse { format: "TOML", .. }));
+
+        let err = PolicyRegistry::from_json_str("{\"policies\": [").unwrap_err();
+        assert!(matches!(err, ConfigError::Parse { format: "JSON", .. }));
+        assert!(err
+            .to_string()
+            .starts_with("failed to parse JSON policy file"));
+    }
+}
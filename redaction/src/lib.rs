@@ -10,22 +10,28 @@
 //! Key rules:
 //! - Use `#[sensitive(Classification)]` for string-like leaf values.
 //! - Use `#[sensitive]` for scalars and nested `Sensitive` types.
-//! - Unannotated fields pass through unchanged.
+//! - Unannotated fields pass through unchanged, unless the container sets
+//!   `#[sensitive(default = Classification)]`.
 //! - `Debug` always prints `"[REDACTED]"` for sensitive fields; policies apply only
 //!   when calling `.redact()`.
 //!
-//! Boxed trait objects:
-//! - `#[sensitive]` supports `Box<dyn Trait>` by calling `redact_boxed`.
-//! - Detection is conservative and only matches the simple `Box<dyn Trait>` syntax,
-//!   not qualified paths or type aliases.
+//! Trait objects:
+//! - `#[sensitive]` supports `Box<dyn Trait>` via `RedactableBoxed`, and `Arc<dyn Trait>` /
+//!   `Rc<dyn Trait>` via `RedactableShared`, including `std::`/`alloc::`-qualified paths.
+//! - Type aliases cannot be detected; mark those fields `#[sensitive(boxed)]`.
 //!
 //! What this crate does:
 //! - defines classification marker types and the [`Classification`] trait
 //! - defines redaction policies and the `redact` entrypoint
 //! - provides integrations behind feature flags (e.g. `slog`)
+//! - optionally loads policy overrides from TOML/JSON (`config` feature) and
+//!   hot-reloads them at runtime (`reload` feature)
+//! - optionally replaces values with reversible tokens backed by a vault
+//!   (`tokenize` feature)
 //!
 //! What it does not do:
-//! - perform I/O or logging
+//! - perform I/O or logging (except reading policy files and writing token
+//!   vaults when asked to)
 //! - validate your policy choices
 //!
 //! The `Sensitive` derive macro lives in `redaction-derive` and is re-exported when
@@ -93,23 +99,40 @@ extern crate self as redact;
 // Module declarations
 #[cfg(feature = "classification")]
 mod classification;
+#[cfg(feature = "config")]
+pub mod config;
 #[cfg(feature = "policy")]
 mod redaction;
+#[cfg(feature = "reload")]
+pub mod reload;
 #[cfg(feature = "slog")]
 pub mod slog;
+#[cfg(feature = "tokenize")]
+pub mod tokenize;
 
 // Re-exports
+#[cfg(feature = "scanner")]
+pub use classification::FreeText;
 #[cfg(feature = "classification")]
 pub use classification::{
     AccountId, BlockchainAddress, Classification, CreditCard, DateOfBirth, Email, IpAddress,
     NationalId, PhoneNumber, Pii, Secret, SessionId, Token,
 };
+#[cfg(feature = "fpe")]
+pub use redaction::FpeConfig;
+#[cfg(feature = "jwt")]
+pub use redaction::JwtConfig;
 #[cfg(feature = "policy")]
 pub use redaction::{
-    apply_classification, redact, redact_boxed, KeepConfig, MaskConfig, Redactable,
-    RedactableBoxed, RedactionPolicy, ScalarRedaction, SensitiveValue, TextRedactionPolicy,
+    apply_classification, redact, redact_arc, redact_arc_mutex, redact_arc_mutex_with,
+    redact_arc_rwlock, redact_arc_rwlock_with, redact_boxed, redact_rc, BirthDateConfig,
+    KeepConfig, MaskConfig, PanConfig, PolicyRegistry, Redactable, RedactableBoxed,
+    RedactableShared, RedactionPolicy, ScalarRedaction, SensitiveValue, SubnetConfig,
+    TextCondition, TextPolicy, TextRedactionPolicy, TruncateConfig, UrlConfig,
     REDACTED_PLACEHOLDER,
 };
 #[doc(hidden)]
 #[cfg(feature = "policy")]
-pub use redaction::{Classifiable, RedactionMapper, SensitiveType};
+pub use redaction::{Classifiable, RedactablePointer, RedactionMapper, SensitiveType};
+#[cfg(feature = "scanner")]
+pub use redaction::{Detector, ScanMatch, TextScanner};
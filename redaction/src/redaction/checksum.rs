@@ -0,0 +1,85 @@
+//! Checksum validation for structured identifiers.
+//!
+//! Detectors and policies use these checks to tell real identifiers apart from
+//! digit runs that merely look like them.
+
+/// Returns `true` if `digits` passes the Luhn (mod 10) check.
+///
+/// Every character must be an ASCII digit; empty input fails.
+pub(crate) fn luhn_valid(digits: &str) -> bool {
+    if digits.is_empty() {
+        return false;
+    }
+    let mut sum = 0u32;
+    for (index, ch) in digits.bytes().rev().enumerate() {
+        if !ch.is_ascii_digit() {
+            return false;
+        }
+        let mut digit = u32::from(ch - b'0');
+        if index % 2 == 1 {
+            digit *= 2;
+            if digit > 9 {
+                digit -= 9;
+            }
+        }
+        sum += digit;
+    }
+    sum.is_multiple_of(10)
+}
+
+/// Returns `true` if `iban` (without separators) passes the ISO 13616 mod-97 check.
+#[cfg(feature = "scanner")]
+pub(crate) fn iban_valid(iban: &str) -> bool {
+    let bytes = iban.as_bytes();
+    if !(15..=34).contains(&bytes.len())
+        || !bytes[..2].iter().all(u8::is_ascii_uppercase)
+        || !bytes[2..4].iter().all(u8::is_ascii_digit)
+    {
+        return false;
+    }
+    let mut remainder = 0u32;
+    for &ch in bytes[4..].iter().chain(&bytes[..4]) {
+        let value = match ch {
+            b'0'..=b'9' => u32::from(ch - b'0'),
+            b'A'..=b'Z' => u32::from(ch - b'A') + 10,
+            _ => return false,
+        };
+        remainder = if value >= 10 {
+            (remainder * 100 + value) % 97
+        } else {
+            (remainder * 10 + value) % 97
+        };
+    }
+    remainder == 1
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "scanner")]
+    use super::iban_valid;
+    use super::luhn_valid;
+
+    #[test]
+    fn luhn_accepts_valid_numbers() {
+        assert!(luhn_valid("4111111111111111"));
+        assert!(luhn_valid("5500005555555559"));
+        assert!(luhn_valid("79927398713"));
+    }
+
+    #[test]
+    fn luhn_rejects_invalid_numbers() {
+        assert!(!luhn_valid("4111111111111112"));
+        assert!(!luhn_valid(""));
+        assert!(!luhn_valid("4111-1111"));
+    }
+
+    #[cfg(feature = "scanner")]
+    #[test]
+    fn iban_checksum() {
+        assert!(iban_valid("GB82WEST12345698765432"));
+        assert!(iban_valid("DE89370400440532013000"));
+        assert!(!iban_valid("GB82WEST12345698765433"));
+        assert!(!iban_valid("gb82WEST12345698765432"));
+        assert!(!iban_valid("GB82"));
+    }
+}
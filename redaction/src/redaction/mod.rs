@@ -5,15 +5,36 @@
 //! - **`sensitive`**: Domain layer - what is sensitive (`SensitiveType`, `SensitiveValue`)
 //! - **`policy`**: Policy layer - how to redact (`RedactionPolicy`, `TextRedactionPolicy`)
 //! - **`redact`**: Application layer - the redaction machinery (`Classifiable`, `RedactionMapper`)
+//! - **`registry`**: Runtime policy overrides (`PolicyRegistry`)
+//! - **`scanner`**: Free-text detection of embedded sensitive values (`TextScanner`)
 //!
 //! Classification markers live in `crate::classification`.
 
+mod checksum;
 mod policy;
 mod redact;
+mod registry;
+#[cfg(feature = "scanner")]
+mod scanner;
 mod sensitive;
 
+#[cfg(feature = "fpe")]
+pub use policy::FpeConfig;
+#[cfg(feature = "jwt")]
+pub use policy::JwtConfig;
 pub use policy::{
-    KeepConfig, MaskConfig, RedactionPolicy, TextRedactionPolicy, REDACTED_PLACEHOLDER,
+    BirthDateConfig, KeepConfig, MaskConfig, PanConfig, RedactionPolicy, SubnetConfig,
+    TextCondition, TextPolicy, TextRedactionPolicy, TruncateConfig, UrlConfig,
+    REDACTED_PLACEHOLDER,
 };
 pub use redact::{apply_classification, redact, Classifiable, RedactionMapper, ScalarRedaction};
-pub use sensitive::{redact_boxed, Redactable, RedactableBoxed, SensitiveType, SensitiveValue};
+#[cfg(feature = "tokenize")]
+pub(crate) use redact::{with_default_mapper, PolicyMapper};
+pub use registry::PolicyRegistry;
+#[cfg(feature = "scanner")]
+pub use scanner::{Detector, ScanMatch, TextScanner};
+pub use sensitive::{
+    redact_arc, redact_arc_mutex, redact_arc_mutex_with, redact_arc_rwlock, redact_arc_rwlock_with,
+    redact_boxed, redact_rc, Redactable, RedactableBoxed, RedactablePointer, RedactableShared,
+    SensitiveType, SensitiveValue,
+};
@@ -0,0 +1,132 @@
+//! Card-number-aware redaction.
+
+use super::super::checksum::luhn_valid;
+
+/// Configuration for redacting payment card numbers (PANs).
+///
+/// Separators (spaces and dashes) are stripped, the digits must form a 13-19
+/// digit number that passes the Luhn check, and only the BIN (first 6) and last
+/// 4 digits stay visible. Anything else is fully redacted, so a mis-tagged
+/// field never leaks part of an unrelated value.
+///
+/// Use [`PanConfig::new`] for the PCI-DSS defaults.
+#[derive(Clone, Copy, Debug)]
+pub struct PanConfig {
+    /// Number of leading digits to keep visible.
+    visible_prefix: usize,
+    /// Number of trailing digits to keep visible.
+    visible_suffix: usize,
+    /// Symbol used to mask the middle digits.
+    mask_char: char,
+}
+
+impl Default for PanConfig {
+    fn default() -> Self {
+        Self {
+            visible_prefix: 6,
+            visible_suffix: 4,
+            mask_char: '*',
+        }
+    }
+}
+
+impl PanConfig {
+    /// Constructs a configuration that keeps the first 6 and last 4 digits.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Keeps a different number of leading and trailing digits visible.
+    ///
+    /// If the spans would cover the whole number, it is fully redacted instead.
+    #[must_use]
+    pub fn with_visible(mut self, visible_prefix: usize, visible_suffix: usize) -> Self {
+        self.visible_prefix = visible_prefix;
+        self.visible_suffix = visible_suffix;
+        self
+    }
+
+    /// Uses a specific masking character.
+    #[must_use]
+    pub fn with_mask_char(mut self, mask_char: char) -> Self {
+        self.mask_char = mask_char;
+        self
+    }
+
+    /// Sets the masking character in place.
+    pub(crate) fn set_mask_char(&mut self, mask_char: char) {
+        self.mask_char = mask_char;
+    }
+
+    /// Applies the policy to a string value.
+    ///
+    /// Returns `None` if `value` is not a valid card number or if the visible
+    /// spans would reveal every digit.
+    pub(crate) fn apply_to(&self, value: &str) -> Option<String> {
+        if !value
+            .chars()
+            .all(|ch| ch.is_ascii_digit() || ch == ' ' || ch == '-')
+        {
+            return None;
+        }
+        let digits: String = value.chars().filter(char::is_ascii_digit).collect();
+        let total = digits.len();
+        if !(13..=19).contains(&total)
+            || !luhn_valid(&digits)
+            || self.visible_prefix + self.visible_suffix >= total
+        {
+            return None;
+        }
+
+        let masked = total - self.visible_prefix - self.visible_suffix;
+        let mut output = String::with_capacity(total);
+        output.push_str(&digits[..self.visible_prefix]);
+        output.extend(std::iter::repeat_n(self.mask_char, masked));
+        output.push_str(&digits[total - self.visible_suffix..]);
+        Some(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PanConfig;
+
+    #[test]
+    fn keeps_bin_and_last_four() {
+        let config = PanConfig::new();
+        assert_eq!(
+            config.apply_to("4111 1111 1111 1111").as_deref(),
+            Some("411111******1111")
+        );
+        assert_eq!(
+            config.apply_to("5500-0055-5555-5559").as_deref(),
+            Some("550000******5559")
+        );
+    }
+
+    #[test]
+    fn rejects_values_that_are_not_card_numbers() {
+        let config = PanConfig::new();
+        assert_eq!(config.apply_to("4111111111111112"), None);
+        assert_eq!(config.apply_to("hunter2-hunter2"), None);
+        assert_eq!(config.apply_to("4111.1111.1111.1111"), None);
+        assert_eq!(config.apply_to("79927398713"), None);
+        assert_eq!(config.apply_to(""), None);
+    }
+
+    #[test]
+    fn never_reveals_every_digit() {
+        let config = PanConfig::new().with_visible(8, 8);
+        assert_eq!(config.apply_to("4111111111111111"), None);
+    }
+
+    #[test]
+    fn respects_mask_char() {
+        let config = PanConfig::new().with_visible(0, 4).with_mask_char('X');
+        assert_eq!(
+            config.apply_to("4111111111111111").as_deref(),
+            Some("XXXXXXXXXXXX1111")
+        );
+    }
+}
@@ -0,0 +1,78 @@
+//! Conditions for composed policies.
+
+use std::borrow::Cow;
+
+/// A predicate on the input of a [`TextRedactionPolicy::When`](super::TextRedactionPolicy::When).
+///
+/// Lengths are counted in Unicode scalar values.
+#[derive(Clone, Debug)]
+pub enum TextCondition {
+    /// The value has fewer than this many characters.
+    ShorterThan(usize),
+    /// The value has more than this many characters.
+    LongerThan(usize),
+    /// The value contains this substring.
+    Contains(Cow<'static, str>),
+}
+
+impl TextCondition {
+    /// Matches values with fewer than `len` characters.
+    #[must_use]
+    pub fn shorter_than(len: usize) -> Self {
+        Self::ShorterThan(len)
+    }
+
+    /// Matches values with more than `len` characters.
+    #[must_use]
+    pub fn longer_than(len: usize) -> Self {
+        Self::LongerThan(len)
+    }
+
+    /// Matches values containing `needle`.
+    #[must_use]
+    pub fn contains<N>(needle: N) -> Self
+    where
+        N: Into<Cow<'static, str>>,
+    {
+        Self::Contains(needle.into())
+    }
+
+    /// Returns `true` if `value` satisfies the condition.
+    #[must_use]
+    pub fn matches(&self, value: &str) -> bool {
+        match self {
+            TextCondition::ShorterThan(len) => value.chars().count() < *len,
+            TextCondition::LongerThan(len) => value.chars().count() > *len,
+            TextCondition::Contains(needle) => value.contains(needle.as_ref()),
+        }
+    }
+}
+
+/// Collapses runs of whitespace into a single space and trims both ends.
+pub(crate) fn normalize_whitespace(value: &str) -> String {
+    value.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{normalize_whitespace, TextCondition};
+
+    #[test]
+    fn conditions_match_expected_values() {
+        assert!(TextCondition::shorter_than(4).matches("äbc"));
+        assert!(!TextCondition::shorter_than(3).matches("äbc"));
+        assert!(TextCondition::longer_than(2).matches("abc"));
+        assert!(!TextCondition::longer_than(3).matches("abc"));
+        assert!(TextCondition::contains("@").matches("a@b"));
+        assert!(!TextCondition::contains("@").matches("ab"));
+    }
+
+    #[test]
+    fn normalizes_whitespace() {
+        assert_eq!(
+            normalize_whitespace("  4111\t1111 \n 1111  "),
+            "4111 1111 1111"
+        );
+        assert_eq!(normalize_whitespace("   "), "");
+    }
+}
@@ -0,0 +1,89 @@
+//! User-defined policies.
+
+use std::fmt;
+
+/// Arbitrary string redaction logic for [`TextRedactionPolicy::Custom`](super::TextRedactionPolicy::Custom).
+///
+/// Implement this for domain-specific formats that the built-in policies do not
+/// cover. Closures of type `Fn(&str) -> String` implement it automatically:
+///
+/// ```rust
+/// use redaction::{Classification, RedactionPolicy, TextRedactionPolicy};
+///
+/// #[derive(Clone, Copy)]
+/// struct BranchAccount;
+/// impl Classification for BranchAccount {}
+/// impl RedactionPolicy for BranchAccount {
+///     fn policy() -> TextRedactionPolicy {
+///         // Keep the branch code before the slash.
+///         TextRedactionPolicy::custom(|value: &str| match value.split_once('/') {
+///             Some((branch, rest)) => format!("{branch}/{}", "*".repeat(rest.len())),
+///             None => "[REDACTED]".to_string(),
+///         })
+///     }
+/// }
+///
+/// assert_eq!(BranchAccount::policy().apply_to("0042/981234"), "0042/******");
+/// ```
+pub trait TextPolicy: Send + Sync {
+    /// Redacts `value`.
+    fn apply(&self, value: &str) -> String;
+
+    /// Redacts `value`, or returns `None` if this policy does not recognize it.
+    ///
+    /// [`TextRedactionPolicy::first_match`](super::TextRedactionPolicy::first_match)
+    /// moves on to the next policy on `None`, and a plain
+    /// [`apply_to`](super::TextRedactionPolicy::apply_to) falls back to full
+    /// redaction. The default recognizes every value.
+    fn try_apply(&self, value: &str) -> Option<String> {
+        Some(self.apply(value))
+    }
+}
+
+impl<F> TextPolicy for F
+where
+    F: Fn(&str) -> String + Send + Sync,
+{
+    fn apply(&self, value: &str) -> String {
+        self(value)
+    }
+}
+
+impl fmt::Debug for dyn TextPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("TextPolicy")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TextPolicy;
+
+    struct DigitsOnly;
+
+    impl TextPolicy for DigitsOnly {
+        fn apply(&self, value: &str) -> String {
+            "#".repeat(value.len())
+        }
+
+        fn try_apply(&self, value: &str) -> Option<String> {
+            value
+                .bytes()
+                .all(|byte| byte.is_ascii_digit())
+                .then(|| self.apply(value))
+        }
+    }
+
+    #[test]
+    fn closures_are_policies() {
+        let policy = |value: &str| value.to_uppercase();
+        assert_eq!(policy.apply("abc"), "ABC");
+        assert_eq!(policy.try_apply("abc").as_deref(), Some("ABC"));
+    }
+
+    #[test]
+    fn implementations_can_decline_input() {
+        assert_eq!(DigitsOnly.try_apply("123").as_deref(), Some("###"));
+        assert_eq!(DigitsOnly.try_apply("12a"), None);
+    }
+}
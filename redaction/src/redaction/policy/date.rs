@@ -0,0 +1,242 @@
+//! Date-of-birth generalization.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// How much of a birth date a [`BirthDateConfig`] keeps.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum DateGeneralization {
+    /// Only the year, e.g. `1987`.
+    Year,
+    /// Only the decade, e.g. `1980s`.
+    Decade,
+    /// The age in bands of the given width, e.g. `30-39` for a width of 10.
+    AgeBand(u8),
+}
+
+/// Configuration for generalizing dates of birth.
+///
+/// Accepts ISO 8601 dates (`1987-06-15`, optionally followed by a time such as
+/// `T00:00:00Z`) and `DD/MM/YYYY`. Values that do not parse as a valid date, or
+/// that lie after the as-of date, are fully redacted.
+///
+/// Age bands are computed against today's date (UTC) unless an as-of date is
+/// set with [`BirthDateConfig::with_as_of`].
+#[derive(Clone, Copy, Debug)]
+pub struct BirthDateConfig {
+    /// What to emit for a valid date.
+    generalization: DateGeneralization,
+    /// The reference date for age calculations, or `None` for today.
+    as_of: Option<CivilDate>,
+}
+
+impl BirthDateConfig {
+    /// Emits only the year of birth.
+    #[must_use]
+    pub fn year() -> Self {
+        Self::new(DateGeneralization::Year)
+    }
+
+    /// Emits only the decade of birth.
+    #[must_use]
+    pub fn decade() -> Self {
+        Self::new(DateGeneralization::Decade)
+    }
+
+    /// Emits the age in bands of `width` years (a width of 0 is treated as 1).
+    #[must_use]
+    pub fn age_band(width: u8) -> Self {
+        Self::new(DateGeneralization::AgeBand(width.max(1)))
+    }
+
+    fn new(generalization: DateGeneralization) -> Self {
+        Self {
+            generalization,
+            as_of: None,
+        }
+    }
+
+    /// Computes ages and rejects future dates relative to a fixed date instead
+    /// of today.
+    ///
+    /// Invalid as-of dates are ignored.
+    #[must_use]
+    pub fn with_as_of(mut self, year: i32, month: u32, day: u32) -> Self {
+        self.as_of = CivilDate::new(year, month, day);
+        self
+    }
+
+    /// Applies the policy to a string value.
+    ///
+    /// Returns `None` if `value` is not a supported date or lies in the future.
+    pub(crate) fn apply_to(&self, value: &str) -> Option<String> {
+        let birth = CivilDate::parse(value.trim())?;
+        let today = self.as_of.unwrap_or_else(CivilDate::today);
+        if birth > today {
+            return None;
+        }
+        Some(match self.generalization {
+            DateGeneralization::Year => birth.year.to_string(),
+            DateGeneralization::Decade => format!("{}s", birth.year - birth.year.rem_euclid(10)),
+            DateGeneralization::AgeBand(width) => {
+                let width = i32::from(width);
+                let mut age = today.year - birth.year;
+                if (today.month, today.day) < (birth.month, birth.day) {
+                    age -= 1;
+                }
+                let start = age - age % width;
+                if width == 1 {
+                    start.to_string()
+                } else {
+                    format!("{start}-{}", start + width - 1)
+                }
+            }
+        })
+    }
+}
+
+/// A proleptic Gregorian calendar date.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct CivilDate {
+    year: i32,
+    month: u32,
+    day: u32,
+}
+
+impl CivilDate {
+    fn new(year: i32, month: u32, day: u32) -> Option<Self> {
+        let leap = (year % 4 == 0 && year % 100 != 0) || year % 400 == 0;
+        let days_in_month = match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if leap => 29,
+            2 => 28,
+            _ => return None,
+        };
+        (1..=days_in_month)
+            .contains(&day)
+            .then_some(Self { year, month, day })
+    }
+
+    /// Parses `YYYY-MM-DD[T...]` or `DD/MM/YYYY`.
+    fn parse(value: &str) -> Option<Self> {
+        let bytes = value.as_bytes();
+        if bytes.len() >= 10 && bytes[4] == b'-' && bytes[7] == b'-' {
+            if bytes.len() > 10 && !matches!(bytes[10], b'T' | b't' | b' ') {
+                return None;
+            }
+            return Self::new(
+                parse_digits(&value[..4])?,
+                parse_digits(&value[5..7])?,
+                parse_digits(&value[8..10])?,
+            );
+        }
+        if bytes.len() == 10 && bytes[2] == b'/' && bytes[5] == b'/' {
+            return Self::new(
+                parse_digits(&value[6..])?,
+                parse_digits(&value[3..5])?,
+                parse_digits(&value[..2])?,
+            );
+        }
+        None
+    }
+
+    /// Returns today's date in UTC.
+    fn today() -> Self {
+        let days = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs() / 86_400);
+        Self::from_days_since_epoch(i64::try_from(days).unwrap_or(i64::MAX))
+    }
+
+    /// Converts days since 1970-01-01 to a civil date (Howard Hinnant's algorithm).
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn from_days_since_epoch(days: i64) -> Self {
+        let z = days + 719_468;
+        let era = z.div_euclid(146_097);
+        let doe = z.rem_euclid(146_097);
+        let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+        let year = yoe + era * 400 + i64::from(month <= 2);
+        Self {
+            year: year as i32,
+            month,
+            day,
+        }
+    }
+}
+
+/// Parses a fixed-width run of ASCII digits.
+fn parse_digits<T>(digits: &str) -> Option<T>
+where
+    T: std::str::FromStr,
+{
+    if !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+    digits.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BirthDateConfig, CivilDate};
+
+    #[test]
+    fn emits_year_and_decade() {
+        let year = BirthDateConfig::year().with_as_of(2024, 1, 1);
+        assert_eq!(year.apply_to("1987-06-15").as_deref(), Some("1987"));
+        assert_eq!(year.apply_to("15/06/1987").as_deref(), Some("1987"));
+
+        let decade = BirthDateConfig::decade().with_as_of(2024, 1, 1);
+        assert_eq!(
+            decade.apply_to("1987-06-15T00:00:00Z").as_deref(),
+            Some("1980s")
+        );
+    }
+
+    #[test]
+    fn age_bands_account_for_birthdays() {
+        let bands = BirthDateConfig::age_band(10).with_as_of(2024, 6, 14);
+        assert_eq!(bands.apply_to("1984-06-15").as_deref(), Some("30-39"));
+        assert_eq!(bands.apply_to("1984-06-14").as_deref(), Some("40-49"));
+
+        let exact = BirthDateConfig::age_band(1).with_as_of(2024, 6, 14);
+        assert_eq!(exact.apply_to("2000-01-01").as_deref(), Some("24"));
+    }
+
+    #[test]
+    fn rejects_invalid_and_future_dates() {
+        let config = BirthDateConfig::year().with_as_of(2024, 1, 1);
+        assert_eq!(config.apply_to("1987-02-30"), None);
+        assert_eq!(config.apply_to("31/13/1987"), None);
+        assert_eq!(config.apply_to("June 15, 1987"), None);
+        assert_eq!(config.apply_to("1987-06-15junk"), None);
+        assert_eq!(config.apply_to("2030-01-01"), None);
+        assert_eq!(config.apply_to("+987-06-15"), None);
+    }
+
+    #[test]
+    fn accepts_leap_days() {
+        let config = BirthDateConfig::year().with_as_of(2024, 1, 1);
+        assert_eq!(config.apply_to("29/02/2000").as_deref(), Some("2000"));
+        assert_eq!(config.apply_to("1900-02-29"), None);
+    }
+
+    #[test]
+    fn converts_days_since_epoch() {
+        assert_eq!(
+            CivilDate::from_days_since_epoch(0),
+            CivilDate::new(1970, 1, 1).unwrap()
+        );
+        assert_eq!(
+            CivilDate::from_days_since_epoch(19_723),
+            CivilDate::new(2024, 1, 1).unwrap()
+        );
+        assert_eq!(
+            CivilDate::from_days_since_epoch(11_016),
+            CivilDate::new(2000, 2, 29).unwrap()
+        );
+    }
+}
@@ -0,0 +1,274 @@
+//! Format-preserving encryption.
+
+use std::fmt;
+
+use ::fpe::ff1::{FlexibleNumeralString, FF1};
+use aes::Aes256;
+
+use super::super::checksum::luhn_valid;
+
+/// Symbols encrypted by an [`FpeConfig`], in numeral order.
+#[derive(Clone, Copy, Debug)]
+enum FpeAlphabet {
+    /// `0-9`.
+    Digits,
+    /// `0-9`, `A-Z`, then `a-z`.
+    Alphanumeric,
+}
+
+impl FpeAlphabet {
+    fn symbols(self) -> &'static [u8] {
+        match self {
+            FpeAlphabet::Digits => b"0123456789",
+            FpeAlphabet::Alphanumeric => {
+                b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz"
+            }
+        }
+    }
+
+    fn numeral(self, ch: char) -> Option<u16> {
+        let position = self
+            .symbols()
+            .iter()
+            .position(|&symbol| ch == char::from(symbol))?;
+        u16::try_from(position).ok()
+    }
+
+    fn symbol(self, numeral: u16) -> char {
+        char::from(self.symbols()[usize::from(numeral)])
+    }
+}
+
+/// Configuration for format-preserving encryption (NIST SP 800-38G FF1 with
+/// AES-256).
+///
+/// Every symbol of the alphabet is encrypted and everything else (spaces,
+/// dashes, dots) stays in place, so `4111 1111 1111 1111` becomes another
+/// 16-digit number with the same grouping. The same key and tweak always give
+/// the same output, and [`FpeConfig::decrypt`] reverses it. Values with other
+/// letters or digits than the alphabet allows, or with too few symbols for FF1
+/// (6 digits or 4 alphanumerics), are fully redacted.
+///
+/// Use [`FpeConfig::digits`] or [`FpeConfig::alphanumeric`] to create
+/// instances. Keep the key secret: it is all that is needed to decrypt.
+#[derive(Clone)]
+pub struct FpeConfig {
+    /// AES-256 key.
+    key: [u8; 32],
+    /// Symbols that are encrypted.
+    alphabet: FpeAlphabet,
+    /// Public domain separator mixed into the encryption.
+    tweak: Vec<u8>,
+    /// Whether the last digit is a Luhn check digit to recompute.
+    luhn: bool,
+}
+
+impl fmt::Debug for FpeConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FpeConfig")
+            .field("key", &"[REDACTED]")
+            .field("alphabet", &self.alphabet)
+            .field("tweak", &self.tweak)
+            .field("luhn", &self.luhn)
+            .finish()
+    }
+}
+
+impl FpeConfig {
+    /// Encrypts ASCII digits, for card numbers, national IDs, and phone numbers.
+    #[must_use]
+    pub fn digits(key: [u8; 32]) -> Self {
+        Self::new(key, FpeAlphabet::Digits)
+    }
+
+    /// Encrypts ASCII letters and digits as one alphabet.
+    ///
+    /// Letters and digits may trade places, so use [`FpeConfig::digits`] for
+    /// values whose validators check which positions hold digits.
+    #[must_use]
+    pub fn alphanumeric(key: [u8; 32]) -> Self {
+        Self::new(key, FpeAlphabet::Alphanumeric)
+    }
+
+    fn new(key: [u8; 32], alphabet: FpeAlphabet) -> Self {
+        Self {
+            key,
+            alphabet,
+            tweak: Vec::new(),
+            luhn: false,
+        }
+    }
+
+    /// Mixes a public tweak into the encryption.
+    ///
+    /// Different tweaks (for example, one per field or tenant) map the same
+    /// value to unrelated outputs under the same key.
+    #[must_use]
+    pub fn with_tweak<T>(mut self, tweak: T) -> Self
+    where
+        T: Into<Vec<u8>>,
+    {
+        self.tweak = tweak.into();
+        self
+    }
+
+    /// Treats the last digit as a Luhn check digit.
+    ///
+    /// The other digits are encrypted and the check digit is recomputed, so
+    /// card numbers stay Luhn-valid. Inputs that fail the Luhn check are fully
+    /// redacted. Has no effect on alphanumeric configurations.
+    #[must_use]
+    pub fn with_luhn_check_digit(mut self) -> Self {
+        self.luhn = true;
+        self
+    }
+
+    /// Applies the policy to a string value.
+    ///
+    /// Returns `None` if `value` cannot be encrypted in its format.
+    pub(crate) fn apply_to(&self, value: &str) -> Option<String> {
+        self.transform(value, true)
+    }
+
+    /// Recovers the original of a value produced by this configuration.
+    ///
+    /// Returns `None` if `value` is not in a format this configuration produces.
+    #[must_use]
+    pub fn decrypt(&self, value: &str) -> Option<String> {
+        self.transform(value, false)
+    }
+
+    fn transform(&self, value: &str, encrypt: bool) -> Option<String> {
+        let mut numerals = Vec::new();
+        for ch in value.chars() {
+            match self.alphabet.numeral(ch) {
+                Some(numeral) => numerals.push(numeral),
+                None if ch.is_alphanumeric() => return None,
+                None => {}
+            }
+        }
+
+        let luhn = self.luhn && matches!(self.alphabet, FpeAlphabet::Digits);
+        if luhn {
+            let digits: String = numerals
+                .iter()
+                .map(|&numeral| self.alphabet.symbol(numeral))
+                .collect();
+            if !luhn_valid(&digits) {
+                return None;
+            }
+            numerals.pop();
+        }
+
+        let radix = u32::try_from(self.alphabet.symbols().len()).ok()?;
+        let ff1 = FF1::<Aes256>::new(&self.key, radix).ok()?;
+        let input = FlexibleNumeralString::from(numerals);
+        let output = if encrypt {
+            ff1.encrypt(&self.tweak, &input)
+        } else {
+            ff1.decrypt(&self.tweak, &input)
+        };
+        let mut output: Vec<u16> = output.ok()?.into();
+        if luhn {
+            output.push(self.check_digit(&output)?);
+        }
+
+        let mut output = output.into_iter();
+        value
+            .chars()
+            .map(|ch| match self.alphabet.numeral(ch) {
+                Some(_) => output.next().map(|numeral| self.alphabet.symbol(numeral)),
+                None => Some(ch),
+            })
+            .collect()
+    }
+
+    /// Returns the Luhn check digit that completes `digits`.
+    fn check_digit(&self, digits: &[u16]) -> Option<u16> {
+        let mut candidate: String = digits
+            .iter()
+            .map(|&numeral| self.alphabet.symbol(numeral))
+            .collect();
+        candidate.push('0');
+        (0..10).find(|&digit| {
+            candidate.pop();
+            candidate.push(self.alphabet.symbol(digit));
+            luhn_valid(&candidate)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{luhn_valid, FpeConfig};
+
+    const KEY: [u8; 32] = [7; 32];
+
+    #[test]
+    fn encrypts_digits_preserving_shape() {
+        let config = FpeConfig::digits(KEY);
+        let encrypted = config.apply_to("4111-1111-1111-1111").unwrap();
+        assert_ne!(encrypted, "4111-1111-1111-1111");
+        assert_eq!(encrypted.len(), 19);
+        assert!(encrypted
+            .chars()
+            .enumerate()
+            .all(|(idx, ch)| if idx % 5 == 4 {
+                ch == '-'
+            } else {
+                ch.is_ascii_digit()
+            }));
+        assert_eq!(config.apply_to("4111-1111-1111-1111").unwrap(), encrypted);
+        assert_eq!(
+            config.decrypt(&encrypted).as_deref(),
+            Some("4111-1111-1111-1111")
+        );
+    }
+
+    #[test]
+    fn key_and_tweak_change_output() {
+        let base = FpeConfig::digits(KEY).apply_to("123456789");
+        assert_ne!(FpeConfig::digits([8; 32]).apply_to("123456789"), base);
+        assert_ne!(
+            FpeConfig::digits(KEY)
+                .with_tweak("ssn")
+                .apply_to("123456789"),
+            base
+        );
+    }
+
+    #[test]
+    fn luhn_mode_keeps_cards_valid() {
+        let config = FpeConfig::digits(KEY).with_luhn_check_digit();
+        let encrypted = config.apply_to("4111 1111 1111 1111").unwrap();
+        assert!(luhn_valid(&encrypted.replace(' ', "")));
+        assert_eq!(
+            config.decrypt(&encrypted).as_deref(),
+            Some("4111 1111 1111 1111")
+        );
+        assert_eq!(config.apply_to("4111 1111 1111 1112"), None);
+    }
+
+    #[test]
+    fn encrypts_alphanumerics() {
+        let config = FpeConfig::alphanumeric(KEY);
+        let encrypted = config.apply_to("AB-123456-c").unwrap();
+        assert_eq!(encrypted.len(), 11);
+        assert_eq!(&encrypted[2..3], "-");
+        assert_eq!(config.decrypt(&encrypted).as_deref(), Some("AB-123456-c"));
+    }
+
+    #[test]
+    fn rejects_unsupported_values() {
+        assert_eq!(FpeConfig::digits(KEY).apply_to("12345"), None);
+        assert_eq!(FpeConfig::digits(KEY).apply_to("AB123456"), None);
+        assert_eq!(FpeConfig::alphanumeric(KEY).apply_to("ÄB123456"), None);
+        assert_eq!(FpeConfig::alphanumeric(KEY).apply_to("ab1"), None);
+    }
+
+    #[test]
+    fn debug_hides_key() {
+        let debug = format!("{:?}", FpeConfig::digits([0xAB; 32]));
+        assert!(!debug.contains("171"));
+    }
+}
@@ -0,0 +1,123 @@
+//! IP-address-aware redaction.
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+/// Configuration for anonymizing IP addresses by truncating them to a subnet.
+///
+/// Host bits beyond the configured prefix length are zeroed, so
+/// `192.168.100.1` becomes `192.168.100.0` with the default IPv4 prefix of
+/// `/24`, and IPv6 addresses keep their first 48 bits. Values that do not parse
+/// as an IP address are fully redacted.
+///
+/// Use [`SubnetConfig::new`] for the defaults.
+#[derive(Clone, Copy, Debug)]
+pub struct SubnetConfig {
+    /// Number of leading IPv4 bits to keep.
+    ipv4_prefix: u8,
+    /// Number of leading IPv6 bits to keep.
+    ipv6_prefix: u8,
+}
+
+impl Default for SubnetConfig {
+    fn default() -> Self {
+        Self {
+            ipv4_prefix: 24,
+            ipv6_prefix: 48,
+        }
+    }
+}
+
+impl SubnetConfig {
+    /// Constructs a configuration that truncates IPv4 to `/24` and IPv6 to `/48`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Keeps the first `prefix` bits of IPv4 addresses (clamped to 32).
+    #[must_use]
+    pub fn with_ipv4_prefix(mut self, prefix: u8) -> Self {
+        self.ipv4_prefix = prefix.min(32);
+        self
+    }
+
+    /// Keeps the first `prefix` bits of IPv6 addresses (clamped to 128).
+    #[must_use]
+    pub fn with_ipv6_prefix(mut self, prefix: u8) -> Self {
+        self.ipv6_prefix = prefix.min(128);
+        self
+    }
+
+    /// Applies the policy to a string value.
+    ///
+    /// Returns `None` if `value` is not an IP address.
+    pub(crate) fn apply_to(self, value: &str) -> Option<String> {
+        let truncated = match value.trim().parse::<IpAddr>().ok()? {
+            IpAddr::V4(addr) => {
+                let mask = u32::MAX
+                    .checked_shl(32 - u32::from(self.ipv4_prefix))
+                    .unwrap_or(0);
+                IpAddr::V4(Ipv4Addr::from(u32::from(addr) & mask))
+            }
+            IpAddr::V6(addr) => {
+                let mask = u128::MAX
+                    .checked_shl(128 - u32::from(self.ipv6_prefix))
+                    .unwrap_or(0);
+                IpAddr::V6(Ipv6Addr::from(u128::from(addr) & mask))
+            }
+        };
+        Some(truncated.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SubnetConfig;
+
+    #[test]
+    fn truncates_ipv4_to_slash_24() {
+        let config = SubnetConfig::new();
+        assert_eq!(
+            config.apply_to("192.168.100.1").as_deref(),
+            Some("192.168.100.0")
+        );
+    }
+
+    #[test]
+    fn truncates_ipv6_to_slash_48() {
+        let config = SubnetConfig::new();
+        assert_eq!(
+            config
+                .apply_to("2001:db8:85a3:8d3:1319:8a2e:370:7348")
+                .as_deref(),
+            Some("2001:db8:85a3::")
+        );
+    }
+
+    #[test]
+    fn prefixes_are_configurable() {
+        let config = SubnetConfig::new()
+            .with_ipv4_prefix(16)
+            .with_ipv6_prefix(32);
+        assert_eq!(config.apply_to("10.20.30.40").as_deref(), Some("10.20.0.0"));
+        assert_eq!(
+            config.apply_to("2001:db8:85a3::1").as_deref(),
+            Some("2001:db8::")
+        );
+        let config = SubnetConfig::new().with_ipv4_prefix(0);
+        assert_eq!(config.apply_to("10.20.30.40").as_deref(), Some("0.0.0.0"));
+        let config = SubnetConfig::new().with_ipv4_prefix(40);
+        assert_eq!(
+            config.apply_to("10.20.30.40").as_deref(),
+            Some("10.20.30.40")
+        );
+    }
+
+    #[test]
+    fn rejects_values_that_are_not_addresses() {
+        let config = SubnetConfig::new();
+        assert_eq!(config.apply_to("localhost"), None);
+        assert_eq!(config.apply_to("256.1.1.1"), None);
+        assert_eq!(config.apply_to(""), None);
+    }
+}
@@ -0,0 +1,160 @@
+//! JWT-aware redaction.
+
+use serde_json::{Map, Value};
+
+/// Claims kept by [`JwtConfig::new`].
+const DEFAULT_CLAIMS: [&str; 5] = ["iss", "aud", "exp", "iat", "nbf"];
+
+/// Configuration for redacting JSON Web Tokens.
+///
+/// The output is the decoded header and the decoded payload restricted to an
+/// allowlist of claims, joined by `.`; the signature is always dropped. For
+/// example, a token for `{"alg":"HS256"}` and
+/// `{"iss":"auth","sub":"alice","exp":1700000000}` becomes
+/// `{"alg":"HS256"}.{"exp":1700000000,"iss":"auth"}`. Values that are not
+/// well-formed JWTs are fully redacted.
+///
+/// Use [`JwtConfig::new`] to keep the registered timing, issuer, and audience
+/// claims (`iss`, `aud`, `exp`, `iat`, `nbf`).
+#[derive(Clone, Debug)]
+pub struct JwtConfig {
+    /// Payload claims that stay readable.
+    claims: Vec<String>,
+}
+
+impl Default for JwtConfig {
+    fn default() -> Self {
+        Self {
+            claims: DEFAULT_CLAIMS.map(String::from).to_vec(),
+        }
+    }
+}
+
+impl JwtConfig {
+    /// Constructs a configuration that keeps `iss`, `aud`, `exp`, `iat`, and `nbf`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Keeps exactly the named payload claims instead of the defaults.
+    #[must_use]
+    pub fn with_claims<I, S>(mut self, claims: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.claims = claims.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Applies the policy to a string value.
+    ///
+    /// Returns `None` if `value` is not a JWT with a JSON header and payload.
+    pub(crate) fn apply_to(&self, value: &str) -> Option<String> {
+        let mut segments = value.trim().split('.');
+        let (header, payload, _signature) = (segments.next()?, segments.next()?, segments.next()?);
+        if segments.next().is_some() {
+            return None;
+        }
+
+        let header = decode_object(header)?;
+        if !header.get("alg").is_some_and(Value::is_string) {
+            return None;
+        }
+        let claims: Map<String, Value> = decode_object(payload)?
+            .into_iter()
+            .filter(|(name, _)| self.claims.iter().any(|kept| kept == name))
+            .collect();
+
+        Some(format!(
+            "{}.{}",
+            Value::Object(header),
+            Value::Object(claims)
+        ))
+    }
+}
+
+/// Decodes a base64url segment holding a JSON object.
+fn decode_object(segment: &str) -> Option<Map<String, Value>> {
+    match serde_json::from_slice(&decode_base64url(segment)?).ok()? {
+        Value::Object(object) => Some(object),
+        _ => None,
+    }
+}
+
+/// Decodes unpadded base64url, as used by JWS compact serialization.
+fn decode_base64url(segment: &str) -> Option<Vec<u8>> {
+    if segment.len() % 4 == 1 {
+        return None;
+    }
+    let mut output = Vec::with_capacity(segment.len() * 3 / 4);
+    let mut buffer = 0u32;
+    let mut bits = 0;
+    for byte in segment.bytes() {
+        let value = match byte {
+            b'A'..=b'Z' => byte - b'A',
+            b'a'..=b'z' => byte - b'a' + 26,
+            b'0'..=b'9' => byte - b'0' + 52,
+            b'-' => 62,
+            b'_' => 63,
+            _ => return None,
+        };
+        buffer = (buffer << 6) | u32::from(value);
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            output.push(u8::try_from((buffer >> bits) & 0xFF).ok()?);
+        }
+    }
+    Some(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode_base64url, JwtConfig};
+
+    // {"alg":"HS256","typ":"JWT"} . {"iss":"auth","sub":"alice","exp":1700000000,"email":"a@b.io"}
+    const TOKEN: &str = "eyJhbGciOiJIUzI1NiIsInR5cCI6IkpXVCJ9.\
+        eyJpc3MiOiJhdXRoIiwic3ViIjoiYWxpY2UiLCJleHAiOjE3MDAwMDAwMDAsImVtYWlsIjoiYUBiLmlvIn0.\
+        c2lnbmF0dXJl";
+
+    #[test]
+    fn keeps_header_and_allowed_claims() {
+        assert_eq!(
+            JwtConfig::new().apply_to(TOKEN).as_deref(),
+            Some(r#"{"alg":"HS256","typ":"JWT"}.{"exp":1700000000,"iss":"auth"}"#)
+        );
+    }
+
+    #[test]
+    fn claim_allowlist_is_configurable() {
+        assert_eq!(
+            JwtConfig::new()
+                .with_claims(["sub"])
+                .apply_to(TOKEN)
+                .as_deref(),
+            Some(r#"{"alg":"HS256","typ":"JWT"}.{"sub":"alice"}"#)
+        );
+    }
+
+    #[test]
+    fn rejects_values_that_are_not_jwts() {
+        let config = JwtConfig::new();
+        assert_eq!(config.apply_to("tok_live_abcdef"), None);
+        assert_eq!(config.apply_to("a.b.c"), None);
+        assert_eq!(config.apply_to(&format!("{TOKEN}.extra")), None);
+        // Header without `alg`: {"typ":"JWT"}
+        assert_eq!(config.apply_to("eyJ0eXAiOiJKV1QifQ.e30.sig"), None);
+    }
+
+    #[test]
+    fn decodes_unpadded_base64url() {
+        assert_eq!(decode_base64url("").as_deref(), Some(&b""[..]));
+        assert_eq!(decode_base64url("Zm8").as_deref(), Some(&b"fo"[..]));
+        assert_eq!(decode_base64url("Zm9v").as_deref(), Some(&b"foo"[..]));
+        assert_eq!(decode_base64url("-_8").as_deref(), Some(&[0xFB, 0xFF][..]));
+        assert_eq!(decode_base64url("Zm9v="), None);
+        assert_eq!(decode_base64url("Z"), None);
+    }
+}
@@ -0,0 +1,141 @@
+//! Length-bounding redaction.
+
+use std::borrow::Cow;
+
+/// How a [`TruncateConfig`] measures its budget.
+#[derive(Clone, Copy, Debug)]
+enum TruncateLimit {
+    /// At most this many Unicode scalar values.
+    Chars(usize),
+    /// At most this many UTF-8 bytes.
+    Bytes(usize),
+}
+
+/// Configuration that keeps the start of a value and cuts the rest.
+///
+/// Values within the budget are returned unchanged. Longer values keep as much
+/// of their start as fits and end with the suffix (`…` by default); the budget
+/// includes the suffix, so the output never exceeds it. If the suffix alone
+/// does not fit, it is left off. Byte budgets never split a character.
+///
+/// Use [`TruncateConfig::chars`] or [`TruncateConfig::bytes`] to create
+/// instances.
+#[derive(Clone, Debug)]
+pub struct TruncateConfig {
+    /// Maximum output length.
+    limit: TruncateLimit,
+    /// Marker appended to truncated values.
+    suffix: Cow<'static, str>,
+}
+
+impl TruncateConfig {
+    /// Limits output to `max_chars` Unicode scalar values.
+    #[must_use]
+    pub fn chars(max_chars: usize) -> Self {
+        Self::new(TruncateLimit::Chars(max_chars))
+    }
+
+    /// Limits output to `max_bytes` UTF-8 bytes.
+    #[must_use]
+    pub fn bytes(max_bytes: usize) -> Self {
+        Self::new(TruncateLimit::Bytes(max_bytes))
+    }
+
+    fn new(limit: TruncateLimit) -> Self {
+        Self {
+            limit,
+            suffix: Cow::Borrowed("…"),
+        }
+    }
+
+    /// Uses a custom marker for truncated values.
+    #[must_use]
+    pub fn with_suffix<S>(mut self, suffix: S) -> Self
+    where
+        S: Into<Cow<'static, str>>,
+    {
+        self.suffix = suffix.into();
+        self
+    }
+
+    /// Applies the policy to a string value.
+    pub(crate) fn apply_to(&self, value: &str) -> String {
+        let (max, len, suffix_len) = match self.limit {
+            TruncateLimit::Chars(max_chars) => (
+                max_chars,
+                value.chars().count(),
+                self.suffix.chars().count(),
+            ),
+            TruncateLimit::Bytes(max_bytes) => (max_bytes, value.len(), self.suffix.len()),
+        };
+        if len <= max {
+            return value.to_string();
+        }
+
+        // Leave the suffix off if it alone does not fit the budget.
+        let (budget, suffix) = match max.checked_sub(suffix_len) {
+            Some(budget) => (budget, self.suffix.as_ref()),
+            None => (max, ""),
+        };
+        let cut = match self.limit {
+            TruncateLimit::Chars(_) => char_boundary_after(value, budget),
+            TruncateLimit::Bytes(_) => floor_char_boundary(value, budget),
+        };
+        format!("{}{suffix}", &value[..cut])
+    }
+}
+
+/// Returns the byte offset just past the first `count` characters of `value`.
+fn char_boundary_after(value: &str, count: usize) -> usize {
+    value
+        .char_indices()
+        .nth(count)
+        .map_or(value.len(), |(idx, _)| idx)
+}
+
+/// Returns the largest character boundary in `value` that is at most `index`.
+fn floor_char_boundary(value: &str, index: usize) -> usize {
+    if index >= value.len() {
+        return value.len();
+    }
+    (0..=index)
+        .rev()
+        .find(|idx| value.is_char_boundary(*idx))
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TruncateConfig;
+
+    #[test]
+    fn short_values_are_unchanged() {
+        assert_eq!(TruncateConfig::chars(5).apply_to("hello"), "hello");
+        assert_eq!(TruncateConfig::bytes(5).apply_to("hello"), "hello");
+        assert_eq!(TruncateConfig::chars(0).apply_to(""), "");
+    }
+
+    #[test]
+    fn truncates_by_chars_including_suffix() {
+        let config = TruncateConfig::chars(6);
+        assert_eq!(config.apply_to("stack trace line"), "stack…");
+        assert_eq!(config.apply_to("ünïcödé!"), "ünïcö…");
+    }
+
+    #[test]
+    fn truncates_by_bytes_on_char_boundaries() {
+        let config = TruncateConfig::bytes(8).with_suffix("...");
+        assert_eq!(config.apply_to("abcdefghij"), "abcde...");
+        // "é" is two bytes; cutting at byte 5 would split it.
+        assert_eq!(config.apply_to("abcdéfghij"), "abcd...");
+        assert!(config.apply_to("ééééé").len() <= 8);
+    }
+
+    #[test]
+    fn drops_suffix_that_does_not_fit() {
+        let config = TruncateConfig::chars(2).with_suffix(" [truncated]");
+        assert_eq!(config.apply_to("abcdef"), "ab");
+        let config = TruncateConfig::bytes(3).with_suffix(" [truncated]");
+        assert_eq!(config.apply_to("aéb"), "aé");
+    }
+}
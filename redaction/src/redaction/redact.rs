@@ -29,12 +29,16 @@
 
 use std::{
     borrow::Cow,
+    cell::RefCell,
     collections::{BTreeMap, BTreeSet, HashMap, HashSet},
     hash::{BuildHasher, Hash},
+    rc::Rc,
+    sync::{Arc, Mutex, PoisonError, RwLock},
 };
 
 use super::{
     policy::RedactionPolicy,
+    registry::PolicyRegistry,
     sensitive::{SensitiveType, SensitiveValue},
 };
 
@@ -63,17 +67,34 @@ pub trait RedactionMapper {
 }
 
 /// The default mapper that applies redaction policies.
-#[derive(Clone, Copy, Debug)]
-struct PolicyMapper;
+///
+/// When a [`PolicyRegistry`] is attached, its overrides take precedence over
+/// the classification's type-level policy.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct PolicyMapper<'a> {
+    registry: Option<&'a PolicyRegistry>,
+}
 
-impl RedactionMapper for PolicyMapper {
+impl<'a> PolicyMapper<'a> {
+    /// Constructs a mapper that consults `registry` before the type-level policies.
+    pub(crate) fn with_registry(registry: &'a PolicyRegistry) -> Self {
+        Self {
+            registry: Some(registry),
+        }
+    }
+}
+
+impl RedactionMapper for PolicyMapper<'_> {
     fn map_sensitive<V, P>(&self, value: V) -> V
     where
         V: SensitiveValue,
         P: RedactionPolicy,
     {
-        let policy = P::policy();
-        let redacted = policy.apply_to(value.as_str());
+        let policy = match self.registry {
+            Some(registry) => registry.policy_for::<P>(),
+            None => P::policy(),
+        };
+        let redacted = policy.apply_with_registry(value.as_str(), self.registry);
         V::from_redacted(redacted)
     }
 
@@ -133,11 +154,22 @@ impl ScalarRedaction for char {
 ///
 /// This function is total: policy application does not propagate errors. Any
 /// failure handling is performed by the selected policy implementation.
+///
+/// With the `reload` feature, overrides from the global policy set
+/// (`redaction::reload::global()`) take precedence over type-level policies.
 pub fn redact<W>(value: W) -> W
 where
     W: SensitiveType,
 {
-    let mapper = PolicyMapper;
+    with_default_mapper(|mapper| value.redact_with(mapper))
+}
+
+/// Redacts a value, preferring the policies in `registry` over type-level defaults.
+pub(crate) fn redact_with_registry<W>(value: W, registry: &PolicyRegistry) -> W
+where
+    W: SensitiveType,
+{
+    let mapper = PolicyMapper::with_registry(registry);
     value.redact_with(&mapper)
 }
 
@@ -149,8 +181,23 @@ where
     C: RedactionPolicy,
     V: Classifiable,
 {
-    let mapper = PolicyMapper;
-    value.apply_classification::<C, _>(&mapper)
+    with_default_mapper(|mapper| value.apply_classification::<C, _>(mapper))
+}
+
+/// Runs `f` with the mapper used by [`redact`] and [`apply_classification`].
+///
+/// With the `reload` feature, the mapper holds one snapshot of the global
+/// policy set for the whole traversal.
+pub(crate) fn with_default_mapper<R>(f: impl FnOnce(&PolicyMapper<'_>) -> R) -> R {
+    #[cfg(feature = "reload")]
+    {
+        let snapshot = crate::reload::global().snapshot();
+        f(&PolicyMapper::with_registry(&snapshot))
+    }
+    #[cfg(not(feature = "reload"))]
+    {
+        f(&PolicyMapper::default())
+    }
 }
 
 // =============================================================================
@@ -166,6 +213,8 @@ where
 /// ## Implementors
 ///
 /// - **Wrapper types** (`Option`, `Vec`, `Box`, maps, sets): Recursively apply to contents
+/// - **Shared and cell types** (`Arc`, `Rc`, `Cow<[T]>`, `RefCell`, `Mutex`, `RwLock`): Same
+///   clone-on-write and poison-tolerant semantics as their `SensitiveType` impls
 /// - **Leaf types** (`String`, `Cow<str>`): Apply the classification policy directly
 ///
 /// ## Example
@@ -256,6 +305,68 @@ impl<T: Classifiable> Classifiable for Box<T> {
     }
 }
 
+impl<T: Classifiable + Clone> Classifiable for Arc<T> {
+    fn apply_classification<C, M>(self, mapper: &M) -> Self
+    where
+        C: RedactionPolicy,
+        M: RedactionMapper,
+    {
+        Arc::new(Arc::unwrap_or_clone(self).apply_classification::<C, M>(mapper))
+    }
+}
+
+impl<T: Classifiable + Clone> Classifiable for Rc<T> {
+    fn apply_classification<C, M>(self, mapper: &M) -> Self
+    where
+        C: RedactionPolicy,
+        M: RedactionMapper,
+    {
+        Rc::new(Rc::unwrap_or_clone(self).apply_classification::<C, M>(mapper))
+    }
+}
+
+impl<T: Classifiable + Clone> Classifiable for Cow<'_, [T]> {
+    fn apply_classification<C, M>(self, mapper: &M) -> Self
+    where
+        C: RedactionPolicy,
+        M: RedactionMapper,
+    {
+        Cow::Owned(self.into_owned().apply_classification::<C, M>(mapper))
+    }
+}
+
+impl<T: Classifiable> Classifiable for RefCell<T> {
+    fn apply_classification<C, M>(self, mapper: &M) -> Self
+    where
+        C: RedactionPolicy,
+        M: RedactionMapper,
+    {
+        RefCell::new(self.into_inner().apply_classification::<C, M>(mapper))
+    }
+}
+
+impl<T: Classifiable> Classifiable for Mutex<T> {
+    fn apply_classification<C, M>(self, mapper: &M) -> Self
+    where
+        C: RedactionPolicy,
+        M: RedactionMapper,
+    {
+        let value = self.into_inner().unwrap_or_else(PoisonError::into_inner);
+        Mutex::new(value.apply_classification::<C, M>(mapper))
+    }
+}
+
+impl<T: Classifiable> Classifiable for RwLock<T> {
+    fn apply_classification<C, M>(self, mapper: &M) -> Self
+    where
+        C: RedactionPolicy,
+        M: RedactionMapper,
+    {
+        let value = self.into_inner().unwrap_or_else(PoisonError::into_inner);
+        RwLock::new(value.apply_classification::<C, M>(mapper))
+    }
+}
+
 impl<T, E> Classifiable for Result<T, E>
 where
     T: Classifiable,
@@ -349,11 +460,30 @@ where
 
 #[cfg(test)]
 mod tests {
-    use std::collections::HashMap;
+    use std::{
+        borrow::Cow,
+        collections::HashMap,
+        sync::{Arc, Mutex},
+    };
 
-    use super::redact;
+    use super::{apply_classification, redact};
     use crate::{Secret, Sensitive};
 
+    #[test]
+    fn apply_classification_through_shared_and_cell_types() {
+        let shared = Arc::new(vec!["token".to_string()]);
+        let redacted = apply_classification::<Secret, _>(Arc::clone(&shared));
+        assert_eq!(*redacted, vec!["[REDACTED]".to_string()]);
+        assert_eq!(*shared, vec!["token".to_string()]);
+
+        let cell = apply_classification::<Secret, _>(Mutex::new(Some("pin".to_string())));
+        assert_eq!(*cell.lock().unwrap(), Some("[REDACTED]".to_string()));
+
+        let names = ["alice".to_string()];
+        let redacted = apply_classification::<Secret, _>(Cow::Borrowed(&names[..]));
+        assert_eq!(redacted[0], "[REDACTED]");
+    }
+
     #[test]
     fn redact_applies_classification_policy() {
         #[derive(Clone, Sensitive)]
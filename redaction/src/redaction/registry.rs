@@ -0,0 +1,180 @@
+//! Runtime policy overrides keyed by classification name.
+//!
+//! Policies are normally bound at the type level through [`RedactionPolicy`].
+//! A [`PolicyRegistry`] replaces those defaults at runtime (for example from a
+//! configuration file) without touching the classification types themselves.
+//! Classifications without an entry keep their type-level policy.
+
+use std::collections::HashMap;
+
+use super::{
+    policy::{RedactionPolicy, TextRedactionPolicy},
+    redact::{redact_with_registry, Classifiable, PolicyMapper},
+    sensitive::SensitiveType,
+};
+use crate::classification::Classification;
+
+/// A set of policies that override the type-level defaults of classifications.
+///
+/// Entries are keyed by [`Classification::name`].
+#[derive(Clone, Debug, Default)]
+pub struct PolicyRegistry {
+    policies: HashMap<String, TextRedactionPolicy>,
+}
+
+impl PolicyRegistry {
+    /// Constructs an empty registry.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Overrides the policy for classification `C`, returning the previous override.
+    pub fn insert<C>(&mut self, policy: TextRedactionPolicy) -> Option<TextRedactionPolicy>
+    where
+        C: Classification,
+    {
+        self.insert_named(C::name(), policy)
+    }
+
+    /// Overrides the policy for classification `C`.
+    #[must_use]
+    pub fn with_policy<C>(mut self, policy: TextRedactionPolicy) -> Self
+    where
+        C: Classification,
+    {
+        self.insert::<C>(policy);
+        self
+    }
+
+    /// Overrides the policy for the classification called `name`.
+    pub(crate) fn insert_named<N>(
+        &mut self,
+        name: N,
+        policy: TextRedactionPolicy,
+    ) -> Option<TextRedactionPolicy>
+    where
+        N: Into<String>,
+    {
+        self.policies.insert(name.into(), policy)
+    }
+
+    /// Returns the override for classification `C`, if any.
+    #[must_use]
+    pub fn get<C>(&self) -> Option<&TextRedactionPolicy>
+    where
+        C: Classification,
+    {
+        self.get_by_name(C::name())
+    }
+
+    /// Returns the override for the classification called `name`, if any.
+    ///
+    /// Only names from [`Classification::name`] overrides are stable; prefer
+    /// [`PolicyRegistry::get`] for classifications that use the default.
+    #[must_use]
+    pub fn get_by_name(&self, name: &str) -> Option<&TextRedactionPolicy> {
+        if self.policies.is_empty() {
+            // Skip hashing on the common path where no overrides are loaded.
+            return None;
+        }
+        self.policies.get(name)
+    }
+
+    /// Returns the effective policy for `C`: the override if present, otherwise
+    /// [`RedactionPolicy::policy`].
+    #[must_use]
+    pub fn policy_for<C>(&self) -> TextRedactionPolicy
+    where
+        C: RedactionPolicy,
+    {
+        self.get::<C>().cloned().unwrap_or_else(C::policy)
+    }
+
+    /// Returns the number of overrides.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.policies.len()
+    }
+
+    /// Returns `true` if the registry holds no overrides.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.policies.is_empty()
+    }
+
+    /// Redacts `value`, preferring this registry's policies over the type-level defaults.
+    #[must_use]
+    pub fn redact<T>(&self, value: T) -> T
+    where
+        T: SensitiveType,
+    {
+        redact_with_registry(value, self)
+    }
+
+    /// Applies classification `C` to `value` using this registry's policies.
+    #[must_use]
+    pub fn apply_classification<C, V>(&self, value: V) -> V
+    where
+        C: RedactionPolicy,
+        V: Classifiable,
+    {
+        value.apply_classification::<C, _>(&PolicyMapper::with_registry(self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PolicyRegistry;
+    use crate::{Email, RedactionPolicy, Secret, Sensitive, TextRedactionPolicy, Token};
+
+    #[derive(Clone, Sensitive)]
+    #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+    struct Credentials {
+        #[sensitive(Secret)]
+        password: String,
+        #[sensitive(Token)]
+        api_key: String,
+    }
+
+    #[test]
+    fn empty_registry_uses_type_level_policies() {
+        let registry = PolicyRegistry::new();
+        assert!(registry.is_empty());
+        let policy = registry.policy_for::<Token>();
+        assert_eq!(
+            policy.apply_to("abcdef"),
+            Token::policy().apply_to("abcdef")
+        );
+    }
+
+    #[test]
+    fn override_replaces_type_level_policy() {
+        let registry = PolicyRegistry::new()
+            .with_policy::<Token>(TextRedactionPolicy::keep_last(2).with_mask_char('#'));
+        assert_eq!(registry.len(), 1);
+        assert_eq!(registry.policy_for::<Token>().apply_to("abcdef"), "####ef");
+        assert!(registry.get::<Email>().is_none());
+        assert!(registry.get_by_name("Token").is_some());
+    }
+
+    #[test]
+    fn redact_uses_overrides_and_falls_back_for_the_rest() {
+        let registry =
+            PolicyRegistry::new().with_policy::<Token>(TextRedactionPolicy::full_with("<token>"));
+        let redacted = registry.redact(Credentials {
+            password: "hunter2".to_string(),
+            api_key: "tok_live_abcdef".to_string(),
+        });
+        assert_eq!(redacted.password, "[REDACTED]");
+        assert_eq!(redacted.api_key, "<token>");
+    }
+
+    #[test]
+    fn apply_classification_uses_overrides() {
+        let registry =
+            PolicyRegistry::new().with_policy::<Secret>(TextRedactionPolicy::full_with("***"));
+        let redacted = registry.apply_classification::<Secret, _>(Some("hunter2".to_string()));
+        assert_eq!(redacted.as_deref(), Some("***"));
+    }
+}
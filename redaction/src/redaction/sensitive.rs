@@ -22,6 +22,24 @@
 //! containers (`Option`, `Vec`, `Box`, maps, sets). When walking into these
 //! containers, they recursively apply redaction to their contents.
 //!
+//! Shared and interior-mutable wrappers are supported too:
+//!
+//! - `Arc<T>` / `Rc<T>` (`T: Clone`): clone-on-write. A uniquely owned value is
+//!   redacted without copying; a shared one is cloned first, so other holders
+//!   never observe the redaction.
+//! - `RefCell<T>`, `Mutex<T>`, `RwLock<T>`: the owned cell is consumed and a new
+//!   cell holds the redacted value. Poisoned locks are recovered, not skipped.
+//! - `Cow<'_, [T]>` (`T: Clone`): redacted into an owned slice.
+//!
+//! Coherence limits what can be covered generically. `Mutex` and `RwLock` are
+//! not `Clone`, so `Arc<Mutex<T>>` cannot go through the `Arc` impl. The
+//! derive recognizes `Arc<Mutex<T>>` and `Arc<RwLock<T>>` fields by their
+//! syntax and calls [`redact_arc_mutex_with`] / [`redact_arc_rwlock_with`] with
+//! the active mapper, so registry overrides and tokenization reach the locked
+//! value. Type aliases hide that syntax; implement [`SensitiveType`] for a
+//! wrapper with the same helpers instead. `Cow<'_, T>` for sized `T` would
+//! overlap the `Cow<'_, str>` impl, so only slices are covered.
+//!
 //! ## External Types
 //!
 //! External types (like `chrono::DateTime`) don't implement `SensitiveType`,
@@ -30,11 +48,14 @@
 
 use std::{
     borrow::Cow,
+    cell::RefCell,
     collections::{BTreeMap, BTreeSet, HashMap, HashSet},
     hash::Hash,
+    rc::Rc,
+    sync::{Arc, Mutex, PoisonError, RwLock},
 };
 
-use super::redact::RedactionMapper;
+use super::redact::{with_default_mapper, RedactionMapper};
 
 // =============================================================================
 // SensitiveValue - Types that ARE sensitive data (leaf values)
@@ -177,6 +198,151 @@ where
     value.redact_boxed()
 }
 
+/// Redacts trait objects behind shared pointers (`Arc<dyn Trait>`, `Rc<dyn Trait>`).
+///
+/// The pointee may be shared, so it cannot be redacted in place; instead the
+/// object returns a redacted copy, which replaces the field's pointer. Other
+/// holders of the original pointer are unaffected. Like [`RedactableBoxed`],
+/// implement it on the trait object type (`impl RedactableShared for dyn Trait`).
+pub trait RedactableShared {
+    /// Returns a redacted copy of the value.
+    #[must_use]
+    fn redacted_copy(&self) -> Box<Self>;
+}
+
+/// Convenience helper for redacting `Arc` trait objects.
+#[must_use]
+pub fn redact_arc<T>(value: Arc<T>) -> Arc<T>
+where
+    T: ?Sized + RedactableShared,
+{
+    Arc::from(value.redacted_copy())
+}
+
+/// Convenience helper for redacting `Rc` trait objects.
+#[must_use]
+pub fn redact_rc<T>(value: Rc<T>) -> Rc<T>
+where
+    T: ?Sized + RedactableShared,
+{
+    Rc::from(value.redacted_copy())
+}
+
+/// Redacts a shared `Arc<Mutex<T>>` with the default policies.
+///
+/// A uniquely owned mutex is consumed; a shared one is locked just long enough
+/// to clone its value, so other holders keep the original. The result is a
+/// new `Arc` with a new mutex.
+///
+/// This uses the same policies as [`Redactable::redact`], so it ignores
+/// registry overrides and tokenization. Derived types do not need it: bare
+/// `#[sensitive]` on an `Arc<Mutex<T>>` field calls [`redact_arc_mutex_with`]
+/// with the active mapper.
+#[must_use]
+pub fn redact_arc_mutex<T>(value: Arc<Mutex<T>>) -> Arc<Mutex<T>>
+where
+    T: SensitiveType + Clone,
+{
+    with_default_mapper(|mapper| redact_arc_mutex_with(value, mapper))
+}
+
+/// Redacts a shared `Arc<Mutex<T>>` with `mapper`.
+///
+/// Behaves like [`redact_arc_mutex`], but applies the policies of the caller's
+/// mapper. The derive calls it for `#[sensitive]` fields of this type; call it
+/// yourself inside a hand-written [`SensitiveType::redact_with`].
+#[must_use]
+pub fn redact_arc_mutex_with<T, M>(value: Arc<Mutex<T>>, mapper: &M) -> Arc<Mutex<T>>
+where
+    T: SensitiveType + Clone,
+    M: RedactionMapper,
+{
+    let inner = match Arc::try_unwrap(value) {
+        Ok(mutex) => mutex.into_inner().unwrap_or_else(PoisonError::into_inner),
+        Err(shared) => shared
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone(),
+    };
+    Arc::new(Mutex::new(inner.redact_with(mapper)))
+}
+
+/// Redacts a shared `Arc<RwLock<T>>` with the default policies.
+///
+/// See [`redact_arc_mutex`]; a shared lock is only held for reading. Bare
+/// `#[sensitive]` on an `Arc<RwLock<T>>` field calls [`redact_arc_rwlock_with`]
+/// instead.
+#[must_use]
+pub fn redact_arc_rwlock<T>(value: Arc<RwLock<T>>) -> Arc<RwLock<T>>
+where
+    T: SensitiveType + Clone,
+{
+    with_default_mapper(|mapper| redact_arc_rwlock_with(value, mapper))
+}
+
+/// Redacts a shared `Arc<RwLock<T>>` with `mapper`.
+///
+/// See [`redact_arc_mutex_with`].
+#[must_use]
+pub fn redact_arc_rwlock_with<T, M>(value: Arc<RwLock<T>>, mapper: &M) -> Arc<RwLock<T>>
+where
+    T: SensitiveType + Clone,
+    M: RedactionMapper,
+{
+    let inner = match Arc::try_unwrap(value) {
+        Ok(lock) => lock.into_inner().unwrap_or_else(PoisonError::into_inner),
+        Err(shared) => shared
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone(),
+    };
+    Arc::new(RwLock::new(inner.redact_with(mapper)))
+}
+
+/// Pointers to trait objects that the derive redacts under `#[sensitive]`.
+///
+/// Implemented for `Box<T>` with `T: RedactableBoxed`, and for `Arc<T>` and
+/// `Rc<T>` with `T: RedactableShared`. Calling through this trait lets
+/// `#[sensitive(boxed)]` handle type aliases without knowing the pointer kind.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a redactable trait object pointer",
+    label = "expected `Box`, `Arc`, or `Rc` of a trait object",
+    note = "`Box<dyn Trait>` needs `Trait: RedactableBoxed`; `Arc`/`Rc` need `Trait: RedactableShared`"
+)]
+#[doc(hidden)]
+pub trait RedactablePointer {
+    /// Redacts the pointee, returning the (possibly new) pointer.
+    #[must_use]
+    fn redact_pointer(self) -> Self;
+}
+
+impl<T> RedactablePointer for Box<T>
+where
+    T: ?Sized + RedactableBoxed,
+{
+    fn redact_pointer(self) -> Self {
+        redact_boxed(self)
+    }
+}
+
+impl<T> RedactablePointer for Arc<T>
+where
+    T: ?Sized + RedactableShared,
+{
+    fn redact_pointer(self) -> Self {
+        redact_arc(self)
+    }
+}
+
+impl<T> RedactablePointer for Rc<T>
+where
+    T: ?Sized + RedactableShared,
+{
+    fn redact_pointer(self) -> Self {
+        redact_rc(self)
+    }
+}
+
 // =============================================================================
 // SensitiveType implementations for standard library types
 // =============================================================================
@@ -257,6 +423,62 @@ where
     }
 }
 
+impl<T> SensitiveType for Arc<T>
+where
+    T: SensitiveType + Clone,
+{
+    fn redact_with<M: RedactionMapper>(self, mapper: &M) -> Self {
+        Arc::new(Arc::unwrap_or_clone(self).redact_with(mapper))
+    }
+}
+
+impl<T> SensitiveType for Rc<T>
+where
+    T: SensitiveType + Clone,
+{
+    fn redact_with<M: RedactionMapper>(self, mapper: &M) -> Self {
+        Rc::new(Rc::unwrap_or_clone(self).redact_with(mapper))
+    }
+}
+
+impl<T> SensitiveType for Cow<'_, [T]>
+where
+    T: SensitiveType + Clone,
+{
+    fn redact_with<M: RedactionMapper>(self, mapper: &M) -> Self {
+        Cow::Owned(self.into_owned().redact_with(mapper))
+    }
+}
+
+impl<T> SensitiveType for RefCell<T>
+where
+    T: SensitiveType,
+{
+    fn redact_with<M: RedactionMapper>(self, mapper: &M) -> Self {
+        RefCell::new(self.into_inner().redact_with(mapper))
+    }
+}
+
+impl<T> SensitiveType for Mutex<T>
+where
+    T: SensitiveType,
+{
+    fn redact_with<M: RedactionMapper>(self, mapper: &M) -> Self {
+        let value = self.into_inner().unwrap_or_else(PoisonError::into_inner);
+        Mutex::new(value.redact_with(mapper))
+    }
+}
+
+impl<T> SensitiveType for RwLock<T>
+where
+    T: SensitiveType,
+{
+    fn redact_with<M: RedactionMapper>(self, mapper: &M) -> Self {
+        let value = self.into_inner().unwrap_or_else(PoisonError::into_inner);
+        RwLock::new(value.redact_with(mapper))
+    }
+}
+
 impl<K, V, S> SensitiveType for HashMap<K, V, S>
 where
     K: Hash + Eq,
@@ -311,11 +533,17 @@ where
 mod tests {
     use std::{
         borrow::Cow,
+        cell::RefCell,
         collections::{BTreeMap, BTreeSet, HashMap, HashSet},
+        rc::Rc,
+        sync::{Arc, Mutex, RwLock},
     };
 
-    use super::{Redactable, SensitiveValue};
-    use crate::{Secret, Sensitive};
+    use super::{
+        redact_arc, redact_arc_mutex, redact_arc_mutex_with, redact_arc_rwlock, redact_rc,
+        Redactable, RedactablePointer, RedactableShared, SensitiveType, SensitiveValue,
+    };
+    use crate::{PolicyRegistry, RedactionMapper, Secret, Sensitive, TextRedactionPolicy};
 
     // =========================================================================
     // SensitiveValue tests
@@ -480,4 +708,131 @@ mod tests {
         assert!(redacted.contains_key(&key));
         assert_eq!(redacted[&key].value, "[REDACTED]");
     }
+
+    // =========================================================================
+    // Shared and interior-mutable wrapper tests
+    // =========================================================================
+
+    fn secret(value: &str) -> SecretString {
+        SecretString {
+            value: value.to_string(),
+        }
+    }
+
+    #[test]
+    fn arc_traversal_does_not_touch_other_holders() {
+        let original = Arc::new(secret("shared"));
+        let redacted = Arc::clone(&original).redact();
+        assert_eq!(redacted.value, "[REDACTED]");
+        assert_eq!(original.value, "shared");
+
+        let unique = Arc::new(secret("unique")).redact();
+        assert_eq!(unique.value, "[REDACTED]");
+    }
+
+    #[test]
+    fn rc_refcell_traversal_redacts_inner() {
+        let session = Rc::new(RefCell::new(secret("session")));
+        let redacted = Rc::clone(&session).redact();
+        assert_eq!(redacted.borrow().value, "[REDACTED]");
+        assert_eq!(session.borrow().value, "session");
+    }
+
+    #[test]
+    fn lock_traversal_recovers_from_poison() {
+        let mutex = Mutex::new(secret("locked"));
+        let _ = std::panic::catch_unwind(|| {
+            let _guard = mutex.lock().unwrap();
+            panic!("poison the mutex");
+        });
+        assert!(mutex.is_poisoned());
+        let redacted = mutex.redact();
+        assert_eq!(redacted.lock().unwrap().value, "[REDACTED]");
+
+        let lock = RwLock::new(secret("locked")).redact();
+        assert_eq!(lock.read().unwrap().value, "[REDACTED]");
+    }
+
+    #[test]
+    fn cow_slice_traversal_redacts_elements() {
+        let values = [secret("first"), secret("second")];
+        let redacted = Cow::Borrowed(&values[..]).redact();
+        assert!(redacted.iter().all(|value| value.value == "[REDACTED]"));
+        assert_eq!(values[0].value, "first");
+    }
+
+    #[test]
+    fn arc_lock_helpers_leave_shared_state_alone() {
+        let cache = Arc::new(Mutex::new(secret("cached")));
+        let redacted = redact_arc_mutex(Arc::clone(&cache));
+        assert_eq!(redacted.lock().unwrap().value, "[REDACTED]");
+        assert_eq!(cache.lock().unwrap().value, "cached");
+
+        let config = redact_arc_rwlock(Arc::new(RwLock::new(secret("config"))));
+        assert_eq!(config.read().unwrap().value, "[REDACTED]");
+    }
+
+    #[test]
+    fn arc_lock_helpers_with_mapper_apply_registry_overrides() {
+        struct Cache(Arc<Mutex<SecretString>>);
+
+        impl SensitiveType for Cache {
+            fn redact_with<M: RedactionMapper>(self, mapper: &M) -> Self {
+                Cache(redact_arc_mutex_with(self.0, mapper))
+            }
+        }
+
+        let registry =
+            PolicyRegistry::new().with_policy::<Secret>(TextRedactionPolicy::full_with("<hidden>"));
+        let cache = registry.redact(Cache(Arc::new(Mutex::new(secret("cached")))));
+        assert_eq!(cache.0.lock().unwrap().value, "<hidden>");
+
+        // The plain helper only knows the default policies
+        let plain = redact_arc_mutex(Arc::new(Mutex::new(secret("cached"))));
+        assert_eq!(plain.lock().unwrap().value, "[REDACTED]");
+    }
+
+    // =========================================================================
+    // Shared trait object tests
+    // =========================================================================
+
+    trait Credential {
+        fn reveal(&self) -> String;
+    }
+
+    struct ApiKey(String);
+
+    impl Credential for ApiKey {
+        fn reveal(&self) -> String {
+            self.0.clone()
+        }
+    }
+
+    impl RedactableShared for ApiKey {
+        fn redacted_copy(&self) -> Box<Self> {
+            Box::new(ApiKey("[REDACTED]".to_string()))
+        }
+    }
+
+    impl RedactableShared for dyn Credential {
+        fn redacted_copy(&self) -> Box<Self> {
+            Box::new(ApiKey(format!("[REDACTED:{}]", self.reveal().len())))
+        }
+    }
+
+    #[test]
+    fn arc_trait_object_is_replaced_not_mutated() {
+        let original: Arc<dyn Credential> = Arc::new(ApiKey("sk_live_1".to_string()));
+        let redacted = redact_arc(Arc::clone(&original));
+        assert_eq!(redacted.reveal(), "[REDACTED:9]");
+        assert_eq!(original.reveal(), "sk_live_1");
+    }
+
+    #[test]
+    fn rc_pointer_dispatches_through_redactable_pointer() {
+        let value: Rc<dyn Credential> = Rc::new(ApiKey("abc".to_string()));
+        assert_eq!(value.redact_pointer().reveal(), "[REDACTED:3]");
+        let sized = redact_rc(Rc::new(ApiKey("abc".to_string())));
+        assert_eq!(sized.reveal(), "[REDACTED]");
+    }
 }
@@ -0,0 +1,343 @@
+//! Hot-reloadable policy sets.
+//!
+//! This module holds policy overrides behind an atomically swappable pointer so
+//! that redaction rules can be tightened at runtime without redeploying.
+//!
+//! It is responsible for:
+//! - Publishing a new [`PolicyRegistry`] atomically. Every redaction takes a
+//!   single snapshot up front, so an in-flight `redact()` never mixes policies
+//!   from before and after a reload.
+//! - Re-reading the policy file on an explicit [`reload`] call or, on Unix, on
+//!   `SIGHUP` via [`reload_on_sighup`].
+//! - Keeping the previous policies in place when a reload fails.
+//!
+//! With the `reload` feature enabled, [`redact`](crate::redact) and
+//! [`Redactable::redact`](crate::Redactable::redact) consult the [`global`] set
+//! before falling back to the type-level policies.
+//!
+//! ```rust,no_run
+//! use redaction::{config::PolicyLoader, reload};
+//!
+//! reload::global().load_file("/etc/myapp/redaction.toml", PolicyLoader::new())?;
+//! # #[cfg(unix)]
+//! let _reloader = reload::reload_on_sighup(|err| eprintln!("policy reload failed: {err}"))?;
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use std::{
+    path::PathBuf,
+    sync::{Arc, LazyLock, Mutex, PoisonError},
+};
+
+use arc_swap::ArcSwap;
+
+use crate::{
+    config::{ConfigError, PolicyLoader},
+    redaction::SensitiveType,
+    PolicyRegistry,
+};
+
+/// Where a [`ReloadablePolicies`] set reads its policies from.
+#[derive(Clone, Debug)]
+struct PolicySource {
+    path: PathBuf,
+    loader: PolicyLoader,
+}
+
+/// A policy set that can be replaced atomically while redactions are running.
+#[derive(Debug, Default)]
+pub struct ReloadablePolicies {
+    current: ArcSwap<PolicyRegistry>,
+    source: Mutex<Option<PolicySource>>,
+}
+
+impl ReloadablePolicies {
+    /// Constructs a set that starts with `registry` and has no backing file.
+    #[must_use]
+    pub fn new(registry: PolicyRegistry) -> Self {
+        Self {
+            current: ArcSwap::from_pointee(registry),
+            source: Mutex::new(None),
+        }
+    }
+
+    /// Constructs a set backed by the policy file at `path`.
+    pub fn from_file<P>(path: P, loader: PolicyLoader) -> Result<Self, ConfigError>
+    where
+        P: Into<PathBuf>,
+    {
+        let policies = Self::default();
+        policies.load_file(path, loader)?;
+        Ok(policies)
+    }
+
+    /// Loads the policy file at `path` and makes it the source for later reloads.
+    ///
+    /// If the file cannot be loaded, both the current policies and the previous
+    /// source are left unchanged.
+    pub fn load_file<P>(&self, path: P, loader: PolicyLoader) -> Result<(), ConfigError>
+    where
+        P: Into<PathBuf>,
+    {
+        let source = PolicySource {
+            path: path.into(),
+            loader,
+        };
+        // Hold the lock while loading so concurrent loads and reloads publish in order.
+        let mut current_source = self.lock_source();
+        let registry = source.loader.load_file(&source.path)?;
+        self.publish(registry);
+        *current_source = Some(source);
+        drop(current_source);
+        Ok(())
+    }
+
+    /// Re-reads the backing policy file and publishes the result.
+    ///
+    /// On error the current policies stay in effect. Without a backing file this
+    /// is a no-op.
+    pub fn reload(&self) -> Result<(), ConfigError> {
+        let source = self.lock_source();
+        let Some(current) = source.as_ref() else {
+            return Ok(());
+        };
+        let registry = current.loader.load_file(&current.path)?;
+        self.publish(registry);
+        drop(source);
+        Ok(())
+    }
+
+    /// Replaces the current policies and detaches the set from its policy file.
+    ///
+    /// Later [`reload`](Self::reload) calls (including `SIGHUP` reloads) are
+    /// no-ops until [`load_file`](Self::load_file) attaches a file again, so a
+    /// registry stored by hand is never silently replaced by the file contents.
+    pub fn store(&self, registry: PolicyRegistry) {
+        let mut source = self.lock_source();
+        self.publish(registry);
+        *source = None;
+        drop(source);
+    }
+
+    fn publish(&self, registry: PolicyRegistry) {
+        self.current.store(Arc::new(registry));
+    }
+
+    /// Returns the current policies.
+    ///
+    /// The snapshot is unaffected by later reloads.
+    #[must_use]
+    pub fn snapshot(&self) -> Arc<PolicyRegistry> {
+        self.current.load_full()
+    }
+
+    /// Redacts `value` against a single snapshot of the current policies.
+    #[must_use]
+    pub fn redact<T>(&self, value: T) -> T
+    where
+        T: SensitiveType,
+    {
+        self.snapshot().redact(value)
+    }
+
+    fn lock_source(&self) -> std::sync::MutexGuard<'_, Option<PolicySource>> {
+        self.source.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+static GLOBAL: LazyLock<ReloadablePolicies> = LazyLock::new(ReloadablePolicies::default);
+
+/// Returns the process-wide policy set used by [`redact`](crate::redact).
+///
+/// It starts empty, so type-level policies apply until policies are loaded.
+pub fn global() -> &'static ReloadablePolicies {
+    &GLOBAL
+}
+
+/// Re-reads the [`global`] policy file.
+pub fn reload() -> Result<(), ConfigError> {
+    global().reload()
+}
+
+/// Reloads the [`global`] policy set whenever the process receives `SIGHUP`.
+///
+/// Reloads run on a background thread. Failures leave the current policies in
+/// place and are passed to `on_error`.
+#[cfg(unix)]
+pub fn reload_on_sighup<F>(on_error: F) -> std::io::Result<SighupReloader>
+where
+    F: Fn(ConfigError) + Send + 'static,
+{
+    use signal_hook::{consts::SIGHUP, iterator::Signals};
+
+    let mut signals = Signals::new([SIGHUP])?;
+    let handle = signals.handle();
+    let thread = std::thread::Builder::new()
+        .name("redaction-reload".to_string())
+        .spawn(move || {
+            for _ in signals.forever() {
+                if let Err(err) = reload() {
+                    on_error(err);
+                }
+            }
+        })?;
+    Ok(SighupReloader { handle, thread })
+}
+
+/// A running `SIGHUP` listener started by [`reload_on_sighup`].
+#[cfg(unix)]
+#[derive(Debug)]
+pub struct SighupReloader {
+    handle: signal_hook::iterator::Handle,
+    thread: std::thread::JoinHandle<()>,
+}
+
+#[cfg(unix)]
+impl SighupReloader {
+    /// Stops listening for `SIGHUP` and waits for the listener thread to exit.
+    pub fn stop(self) {
+        self.handle.close();
+        // The listener only exits by returning, so a join error means it panicked
+        // inside `on_error`; there is nothing left to clean up in that case.
+        let _ = self.thread.join();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        fs,
+        path::PathBuf,
+        sync::atomic::{AtomicUsize, Ordering},
+    };
+
+    use super::{global, ReloadablePolicies};
+    use crate::{
+        config::{ConfigError, PolicyLoader},
+        Classification, PolicyRegistry, RedactionPolicy, Secret, Sensitive, TextRedactionPolicy,
+        Token,
+    };
+
+    fn temp_policy_file(extension: &str, contents: &str) -> PathBuf {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+        let path = std::env::temp_dir().join(format!(
+            "redaction-reload-{}-{}.{extension}",
+            std::process::id(),
+            COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn reload_picks_up_file_changes() {
+        let path = temp_policy_file("toml", "[policies]\nToken = { keep_last = 4 }");
+        let policies = ReloadablePolicies::from_file(&path, PolicyLoader::new()).unwrap();
+        assert_eq!(
+            policies.snapshot().policy_for::<Token>().apply_to("abcdef"),
+            "**cdef"
+        );
+
+        fs::write(&path, "[policies]\nToken = { full = \"<token>\" }").unwrap();
+        policies.reload().unwrap();
+        assert_eq!(
+            policies.snapshot().policy_for::<Token>().apply_to("abcdef"),
+            "<token>"
+        );
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn failed_reload_keeps_previous_policies() {
+        let path = temp_policy_file("json", r#"{"policies": {"Token": {"full": "<token>"}}}"#);
+        let policies = ReloadablePolicies::from_file(&path, PolicyLoader::new()).unwrap();
+
+        fs::write(&path, r#"{"policies": {"Tokn": {"full": true}}}"#).unwrap();
+        let err = policies.reload().unwrap_err();
+        assert!(matches!(err, ConfigError::UnknownClassification { .. }));
+        assert_eq!(
+            policies.snapshot().policy_for::<Token>().apply_to("abcdef"),
+            "<token>"
+        );
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn snapshots_are_isolated_from_later_stores() {
+        let policies = ReloadablePolicies::new(
+            PolicyRegistry::new().with_policy::<Secret>(TextRedactionPolicy::full_with("old")),
+        );
+        let snapshot = policies.snapshot();
+        policies.store(
+            PolicyRegistry::new().with_policy::<Secret>(TextRedactionPolicy::full_with("new")),
+        );
+        assert_eq!(snapshot.policy_for::<Secret>().apply_to("x"), "old");
+        assert_eq!(
+            policies.snapshot().policy_for::<Secret>().apply_to("x"),
+            "new"
+        );
+    }
+
+    #[test]
+    fn store_detaches_the_policy_file() {
+        let path = temp_policy_file("toml", "[policies]\nToken = { full = \"<file>\" }");
+        let policies = ReloadablePolicies::from_file(&path, PolicyLoader::new()).unwrap();
+        policies.store(
+            PolicyRegistry::new().with_policy::<Token>(TextRedactionPolicy::full_with("<manual>")),
+        );
+
+        policies.reload().unwrap();
+        assert_eq!(
+            policies.snapshot().policy_for::<Token>().apply_to("abcdef"),
+            "<manual>"
+        );
+
+        // Loading the file again re-attaches it
+        policies.load_file(&path, PolicyLoader::new()).unwrap();
+        fs::write(&path, "[policies]\nToken = { full = \"<edited>\" }").unwrap();
+        policies.reload().unwrap();
+        assert_eq!(
+            policies.snapshot().policy_for::<Token>().apply_to("abcdef"),
+            "<edited>"
+        );
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn reload_without_source_is_a_no_op() {
+        let policies = ReloadablePolicies::default();
+        policies.reload().unwrap();
+        assert!(policies.snapshot().is_empty());
+    }
+
+    #[test]
+    fn redact_consults_global_policies() {
+        // A dedicated classification keeps this test from affecting others that
+        // run concurrently against the global set.
+        #[derive(Clone, Copy)]
+        struct ReloadProbe;
+        impl Classification for ReloadProbe {}
+        impl RedactionPolicy for ReloadProbe {
+            fn policy() -> TextRedactionPolicy {
+                TextRedactionPolicy::keep_last(2)
+            }
+        }
+
+        #[derive(Clone, Sensitive)]
+        #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+        struct Probe {
+            #[sensitive(ReloadProbe)]
+            value: String,
+        }
+
+        let probe = || Probe {
+            value: "abcdef".to_string(),
+        };
+        assert_eq!(crate::redact(probe()).value, "****ef");
+
+        let mut registry = PolicyRegistry::clone(&global().snapshot());
+        registry.insert::<ReloadProbe>(TextRedactionPolicy::full_with("<probe>"));
+        global().store(registry);
+        assert_eq!(crate::redact(probe()).value, "<probe>");
+    }
+}
@@ -0,0 +1,570 @@
+//! Deterministic, reversible tokenization of classified values.
+//!
+//! Full redaction makes a value unrecoverable. Under audit, support tooling
+//! sometimes needs the original back (for example the customer email behind a
+//! log line). A [`Tokenizer`] replaces selected classifications with opaque
+//! tokens such as `tok_8f2a41c09d3e7b65a1c0f4e2d9b8a733` and records the
+//! token-to-original mapping in a [`TokenVault`].
+//!
+//! It is responsible for:
+//! - Deriving tokens with HMAC-SHA256 under a secret key, so the same value
+//!   always maps to the same token and log lines stay joinable.
+//! - Storing mappings in a pluggable vault ([`InMemoryVault`], [`FileVault`],
+//!   or your own implementation).
+//! - Recovering originals through [`Tokenizer::detokenize`].
+//!
+//! Classifications that are not registered with
+//! [`Tokenizer::with_classification`] keep their normal policies. If the vault
+//! cannot store a mapping, the value is fully redacted instead of being
+//! written out unprotected.
+//!
+//! ```rust
+//! use redaction::{tokenize::{InMemoryVault, Tokenizer}, Email};
+//!
+//! let tokenizer = Tokenizer::new(b"load-this-key-from-a-secret-store".to_vec(), InMemoryVault::new())
+//!     .with_classification::<Email>();
+//! let token = tokenizer.tokenize("alice@example.com")?;
+//! assert!(token.starts_with("tok_"));
+//! assert_eq!(tokenizer.tokenize("alice@example.com")?, token);
+//! assert_eq!(
+//!     tokenizer.detokenize(&token)?.as_deref(),
+//!     Some("alice@example.com")
+//! );
+//! # Ok::<(), redaction::tokenize::VaultError>(())
+//! ```
+
+use std::{
+    collections::{BTreeSet, HashMap},
+    fmt,
+    fs::{File, OpenOptions},
+    io::{self, Read, Write},
+    path::{Path, PathBuf},
+    sync::{Mutex, PoisonError, RwLock},
+};
+
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use crate::{
+    redaction::{with_default_mapper, PolicyMapper, ScalarRedaction, SensitiveValue},
+    Classification, RedactionMapper, RedactionPolicy, SensitiveType, REDACTED_PLACEHOLDER,
+};
+
+/// Prefix of every token produced by a [`Tokenizer`].
+pub const TOKEN_PREFIX: &str = "tok_";
+
+const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+/// An error raised by a [`TokenVault`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VaultError {
+    message: String,
+}
+
+impl VaultError {
+    /// Constructs an error with a human-readable description.
+    #[must_use]
+    pub fn new<M>(message: M) -> Self
+    where
+        M: Into<String>,
+    {
+        Self {
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for VaultError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "token vault error: {}", self.message)
+    }
+}
+
+impl std::error::Error for VaultError {}
+
+impl From<io::Error> for VaultError {
+    fn from(err: io::Error) -> Self {
+        Self::new(err.to_string())
+    }
+}
+
+/// Storage for token-to-original mappings.
+///
+/// Implementations must be safe to share between threads. Storing the same
+/// token twice always carries the same original, so `store` may treat repeats
+/// as no-ops.
+pub trait TokenVault: Send + Sync {
+    /// Records that `token` stands for `original`.
+    fn store(&self, token: &str, original: &str) -> Result<(), VaultError>;
+
+    /// Returns the original for `token`, if the vault knows it.
+    fn lookup(&self, token: &str) -> Result<Option<String>, VaultError>;
+}
+
+/// A vault that keeps mappings in memory for the life of the process.
+#[derive(Debug, Default)]
+pub struct InMemoryVault {
+    entries: RwLock<HashMap<String, String>>,
+}
+
+impl InMemoryVault {
+    /// Constructs an empty vault.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of stored mappings.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .len()
+    }
+
+    /// Returns `true` if the vault holds no mappings.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl TokenVault for InMemoryVault {
+    fn store(&self, token: &str, original: &str) -> Result<(), VaultError> {
+        self.entries
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .entry(token.to_string())
+            .or_insert_with(|| original.to_string());
+        Ok(())
+    }
+
+    fn lookup(&self, token: &str) -> Result<Option<String>, VaultError> {
+        Ok(self
+            .entries
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(token)
+            .cloned())
+    }
+}
+
+/// A vault that appends mappings to a file and reloads them on open.
+///
+/// Each line holds a token and its original separated by a tab; tabs,
+/// newlines, carriage returns, and backslashes in originals are escaped. The
+/// file contains recoverable sensitive data, so protect it like the data
+/// itself; on Unix a new file is created readable by its owner only (`0o600`).
+///
+/// A final line without a trailing newline, left by a crash mid-write, is
+/// discarded on open and trimmed from the file.
+#[derive(Debug)]
+pub struct FileVault {
+    path: PathBuf,
+    state: Mutex<FileVaultState>,
+}
+
+#[derive(Debug)]
+struct FileVaultState {
+    entries: HashMap<String, String>,
+    file: File,
+}
+
+impl FileVault {
+    /// Opens the vault file at `path`, creating it if it does not exist.
+    pub fn open<P>(path: P) -> Result<Self, VaultError>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref().to_path_buf();
+        let mut options = OpenOptions::new();
+        options.create(true).read(true).append(true);
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+        let mut file = options.open(&path)?;
+
+        let mut contents = Vec::new();
+        file.read_to_end(&mut contents)?;
+        // Only newline-terminated records were written completely
+        let complete = contents
+            .iter()
+            .rposition(|&byte| byte == b'\n')
+            .map_or(0, |index| index + 1);
+        if complete < contents.len() {
+            file.set_len(complete as u64)?;
+        }
+        let contents = std::str::from_utf8(&contents[..complete]).map_err(|_| {
+            VaultError::new(format!("{}: entries are not valid UTF-8", path.display()))
+        })?;
+
+        let mut entries = HashMap::new();
+        for (index, line) in contents.lines().enumerate() {
+            if line.is_empty() {
+                continue;
+            }
+            let (token, escaped) = line.split_once('\t').ok_or_else(|| {
+                VaultError::new(format!(
+                    "{}: malformed entry on line {}",
+                    path.display(),
+                    index + 1
+                ))
+            })?;
+            entries.insert(token.to_string(), unescape(escaped));
+        }
+        Ok(Self {
+            path,
+            state: Mutex::new(FileVaultState { entries, file }),
+        })
+    }
+
+    /// Returns the path of the vault file.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl TokenVault for FileVault {
+    fn store(&self, token: &str, original: &str) -> Result<(), VaultError> {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        if state.entries.contains_key(token) {
+            return Ok(());
+        }
+        let line = format!("{token}\t{}\n", escape(original));
+        state.file.write_all(line.as_bytes())?;
+        state.file.flush()?;
+        state
+            .entries
+            .insert(token.to_string(), original.to_string());
+        drop(state);
+        Ok(())
+    }
+
+    fn lookup(&self, token: &str) -> Result<Option<String>, VaultError> {
+        Ok(self
+            .state
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .entries
+            .get(token)
+            .cloned())
+    }
+}
+
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for ch in value.chars() {
+        match ch {
+            '\\' => escaped.push_str("\\\\"),
+            '\t' => escaped.push_str("\\t"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+fn unescape(value: &str) -> String {
+    let mut unescaped = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(ch) = chars.next() {
+        if ch != '\\' {
+            unescaped.push(ch);
+            continue;
+        }
+        match chars.next() {
+            Some('t') => unescaped.push('\t'),
+            Some('n') => unescaped.push('\n'),
+            Some('r') => unescaped.push('\r'),
+            Some(other) => unescaped.push(other),
+            None => unescaped.push('\\'),
+        }
+    }
+    unescaped
+}
+
+/// Replaces values of selected classifications with deterministic tokens.
+///
+/// Tokens are `tok_` followed by the first 128 bits of
+/// HMAC-SHA256(`key`, value) in hex. Keep the key secret: anyone holding it
+/// can confirm guesses for low-entropy values such as email addresses.
+pub struct Tokenizer<V> {
+    key: Vec<u8>,
+    vault: V,
+    classifications: BTreeSet<&'static str>,
+}
+
+impl<V> fmt::Debug for Tokenizer<V>
+where
+    V: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Tokenizer")
+            .field("key", &"[REDACTED]")
+            .field("vault", &self.vault)
+            .field("classifications", &self.classifications)
+            .finish()
+    }
+}
+
+impl<V> Tokenizer<V>
+where
+    V: TokenVault,
+{
+    /// Constructs a tokenizer that derives tokens with `key` and records them in `vault`.
+    ///
+    /// No classification is tokenized until registered with
+    /// [`Tokenizer::with_classification`].
+    #[must_use]
+    pub fn new<K>(key: K, vault: V) -> Self
+    where
+        K: Into<Vec<u8>>,
+    {
+        Self {
+            key: key.into(),
+            vault,
+            classifications: BTreeSet::new(),
+        }
+    }
+
+    /// Tokenizes values of classification `C` instead of applying its policy.
+    #[must_use]
+    pub fn with_classification<C>(mut self) -> Self
+    where
+        C: Classification,
+    {
+        self.classifications.insert(C::name());
+        self
+    }
+
+    /// Returns the vault that records this tokenizer's mappings.
+    #[must_use]
+    pub fn vault(&self) -> &V {
+        &self.vault
+    }
+
+    /// Returns the token for `value` without recording it.
+    #[must_use]
+    #[allow(clippy::missing_panics_doc)]
+    pub fn token_for(&self, value: &str) -> String {
+        let mut mac =
+            Hmac::<Sha256>::new_from_slice(&self.key).expect("HMAC accepts keys of any length");
+        mac.update(value.as_bytes());
+        let digest = mac.finalize().into_bytes();
+        let mut token = String::with_capacity(TOKEN_PREFIX.len() + 32);
+        token.push_str(TOKEN_PREFIX);
+        for byte in &digest[..16] {
+            token.push(char::from(HEX_DIGITS[usize::from(byte >> 4)]));
+            token.push(char::from(HEX_DIGITS[usize::from(byte & 0x0f)]));
+        }
+        token
+    }
+
+    /// Returns the token for `value` and records the mapping in the vault.
+    pub fn tokenize(&self, value: &str) -> Result<String, VaultError> {
+        let token = self.token_for(value);
+        self.vault.store(&token, value)?;
+        Ok(token)
+    }
+
+    /// Returns the original value behind `token`, if the vault knows it.
+    pub fn detokenize(&self, token: &str) -> Result<Option<String>, VaultError> {
+        self.vault.lookup(token)
+    }
+
+    /// Redacts `value`, tokenizing registered classifications and applying the
+    /// usual policies to the rest.
+    #[must_use]
+    pub fn redact<T>(&self, value: T) -> T
+    where
+        T: SensitiveType,
+    {
+        with_default_mapper(|fallback| {
+            value.redact_with(&TokenizingMapper {
+                tokenizer: self,
+                fallback,
+            })
+        })
+    }
+}
+
+/// Maps registered classifications to tokens and defers everything else.
+struct TokenizingMapper<'a, V> {
+    tokenizer: &'a Tokenizer<V>,
+    fallback: &'a PolicyMapper<'a>,
+}
+
+impl<V> RedactionMapper for TokenizingMapper<'_, V>
+where
+    V: TokenVault,
+{
+    fn map_sensitive<S, P>(&self, value: S) -> S
+    where
+        S: SensitiveValue,
+        P: RedactionPolicy,
+    {
+        if !self.tokenizer.classifications.contains(P::name()) {
+            return self.fallback.map_sensitive::<S, P>(value);
+        }
+        match self.tokenizer.tokenize(value.as_str()) {
+            Ok(token) => S::from_redacted(token),
+            // Never emit a token the vault cannot resolve, and never the original.
+            Err(_) => S::from_redacted(REDACTED_PLACEHOLDER.to_string()),
+        }
+    }
+
+    fn map_scalar<S>(&self, value: S) -> S
+    where
+        S: Default + ScalarRedaction,
+    {
+        self.fallback.map_scalar(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        fs,
+        io::Write,
+        sync::atomic::{AtomicUsize, Ordering},
+    };
+
+    use super::{FileVault, InMemoryVault, TokenVault, Tokenizer, VaultError};
+    use crate::{Email, Secret, Sensitive, REDACTED_PLACEHOLDER};
+
+    #[derive(Clone, Sensitive)]
+    #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+    struct Account {
+        #[sensitive(Email)]
+        email: String,
+        #[sensitive(Secret)]
+        password: String,
+    }
+
+    fn account(email: &str) -> Account {
+        Account {
+            email: email.to_string(),
+            password: "hunter2".to_string(),
+        }
+    }
+
+    struct BrokenVault;
+
+    impl TokenVault for BrokenVault {
+        fn store(&self, _token: &str, _original: &str) -> Result<(), VaultError> {
+            Err(VaultError::new("unavailable"))
+        }
+
+        fn lookup(&self, _token: &str) -> Result<Option<String>, VaultError> {
+            Err(VaultError::new("unavailable"))
+        }
+    }
+
+    #[test]
+    fn tokens_are_deterministic_and_keyed() {
+        let tokenizer = Tokenizer::new("key-a", InMemoryVault::new());
+        let token = tokenizer.token_for("alice@example.com");
+        assert!(token.starts_with("tok_"));
+        assert_eq!(token.len(), 4 + 32);
+        assert_eq!(token, tokenizer.token_for("alice@example.com"));
+        assert_ne!(token, tokenizer.token_for("bob@example.com"));
+
+        let other_key = Tokenizer::new("key-b", InMemoryVault::new());
+        assert_ne!(token, other_key.token_for("alice@example.com"));
+    }
+
+    #[test]
+    fn redact_tokenizes_registered_classifications_only() {
+        let tokenizer = Tokenizer::new("key", InMemoryVault::new()).with_classification::<Email>();
+        let redacted = tokenizer.redact(account("alice@example.com"));
+        assert_eq!(redacted.email, tokenizer.token_for("alice@example.com"));
+        assert_eq!(redacted.password, REDACTED_PLACEHOLDER);
+        assert_eq!(
+            tokenizer.detokenize(&redacted.email).unwrap().as_deref(),
+            Some("alice@example.com")
+        );
+        assert_eq!(tokenizer.vault().len(), 1);
+        assert_eq!(tokenizer.detokenize("tok_unknown").unwrap(), None);
+    }
+
+    #[test]
+    fn vault_failure_falls_back_to_full_redaction() {
+        let tokenizer = Tokenizer::new("key", BrokenVault).with_classification::<Email>();
+        let redacted = tokenizer.redact(account("alice@example.com"));
+        assert_eq!(redacted.email, REDACTED_PLACEHOLDER);
+    }
+
+    #[test]
+    fn file_vault_persists_and_escapes() {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+        let path = std::env::temp_dir().join(format!(
+            "redaction-vault-{}-{}.tsv",
+            std::process::id(),
+            COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        let tricky = "line one\n\ttabbed \\ backslash\r";
+
+        let token = {
+            let tokenizer = Tokenizer::new("key", FileVault::open(&path).unwrap());
+            let token = tokenizer.tokenize(tricky).unwrap();
+            // Repeats are not written twice.
+            tokenizer.tokenize(tricky).unwrap();
+            token
+        };
+        assert_eq!(fs::read_to_string(&path).unwrap().lines().count(), 1);
+
+        let reopened = Tokenizer::new("key", FileVault::open(&path).unwrap());
+        assert_eq!(
+            reopened.detokenize(&token).unwrap().as_deref(),
+            Some(tricky)
+        );
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn file_vault_discards_truncated_final_record() {
+        let path = std::env::temp_dir().join(format!(
+            "redaction-vault-{}-truncated.tsv",
+            std::process::id()
+        ));
+        let token = {
+            let tokenizer = Tokenizer::new("key", FileVault::open(&path).unwrap());
+            tokenizer.tokenize("alice@example.com").unwrap()
+        };
+        let mut file = fs::OpenOptions::new().append(true).open(&path).unwrap();
+        file.write_all(b"tok_partial").unwrap();
+        drop(file);
+
+        let reopened = Tokenizer::new("key", FileVault::open(&path).unwrap());
+        assert_eq!(
+            reopened.detokenize(&token).unwrap().as_deref(),
+            Some("alice@example.com")
+        );
+        reopened.tokenize("bob@example.com").unwrap();
+        let contents = fs::read_to_string(&path).unwrap();
+        assert!(!contents.contains("tok_partial"));
+        assert_eq!(contents.lines().count(), 2);
+        fs::remove_file(path).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn file_vault_is_created_owner_only() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let path =
+            std::env::temp_dir().join(format!("redaction-vault-{}-mode.tsv", std::process::id()));
+        drop(FileVault::open(&path).unwrap());
+        let mode = fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn debug_hides_key() {
+        let tokenizer = Tokenizer::new("super-secret-key", InMemoryVault::new());
+        assert!(!format!("{tokenizer:?}").contains("super-secret-key"));
+    }
+}
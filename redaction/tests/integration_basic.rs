@@ -323,6 +323,154 @@ fn test_box_traversal() {
     assert_eq!(redacted.value, "[REDACTED]");
 }
 
+#[test]
+fn test_trait_object_pointers() {
+    use std::{rc::Rc, sync::Arc};
+
+    use redaction::{RedactableBoxed, RedactableShared};
+
+    trait Credential: std::fmt::Debug {
+        fn reveal(&self) -> String;
+    }
+
+    #[derive(Debug)]
+    struct ApiKey(String);
+
+    impl Credential for ApiKey {
+        fn reveal(&self) -> String {
+            self.0.clone()
+        }
+    }
+
+    // Implemented on the trait object types, which the derive calls through
+    impl RedactableBoxed for dyn Credential {
+        fn redact_boxed(self: Box<Self>) -> Box<Self> {
+            Box::new(ApiKey("[REDACTED]".into()))
+        }
+    }
+
+    impl RedactableShared for dyn Credential + Send + Sync {
+        fn redacted_copy(&self) -> Box<Self> {
+            Box::new(ApiKey("[REDACTED]".into()))
+        }
+    }
+
+    impl RedactableShared for dyn Credential {
+        fn redacted_copy(&self) -> Box<Self> {
+            Box::new(ApiKey("[REDACTED]".into()))
+        }
+    }
+
+    type Handle = Rc<dyn Credential>;
+
+    #[derive(Sensitive)]
+    struct Vault {
+        #[sensitive]
+        boxed: std::boxed::Box<dyn Credential>,
+        #[sensitive]
+        shared: Arc<dyn Credential + Send + Sync>,
+        #[sensitive(boxed)]
+        handle: Handle,
+    }
+
+    // Trait object fields cannot derive the `Clone` and `Serialize` the slog impl requires
+    impl Clone for Vault {
+        fn clone(&self) -> Self {
+            Self {
+                boxed: Box::new(ApiKey(self.boxed.reveal())),
+                shared: Arc::clone(&self.shared),
+                handle: Rc::clone(&self.handle),
+            }
+        }
+    }
+
+    impl serde::Serialize for Vault {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            use serde::ser::SerializeStruct;
+
+            let mut state = serializer.serialize_struct("Vault", 3)?;
+            state.serialize_field("boxed", &self.boxed.reveal())?;
+            state.serialize_field("shared", &self.shared.reveal())?;
+            state.serialize_field("handle", &self.handle.reveal())?;
+            state.end()
+        }
+    }
+
+    let shared: Arc<dyn Credential + Send + Sync> = Arc::new(ApiKey("sk_shared".into()));
+    let redacted = Vault {
+        boxed: Box::new(ApiKey("sk_boxed".into())),
+        shared: Arc::clone(&shared),
+        handle: Rc::new(ApiKey("sk_handle".into())),
+    }
+    .redact();
+    assert_eq!(redacted.boxed.reveal(), "[REDACTED]");
+    assert_eq!(redacted.shared.reveal(), "[REDACTED]");
+    assert_eq!(redacted.handle.reveal(), "[REDACTED]");
+    // Other holders of the shared pointer keep the original value
+    assert_eq!(shared.reveal(), "sk_shared");
+}
+
+#[test]
+fn test_shared_and_cell_fields() {
+    use std::{
+        cell::RefCell,
+        rc::Rc,
+        sync::{Arc, Mutex, RwLock},
+    };
+
+    #[derive(Clone, Sensitive)]
+    #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+    struct Config {
+        #[sensitive(Secret)]
+        api_key: String,
+    }
+
+    #[derive(Clone, Sensitive)]
+    #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+    struct Request {
+        #[sensitive]
+        config: Arc<Config>,
+        #[sensitive]
+        session: Rc<RefCell<Config>>,
+        #[sensitive(Token)]
+        tags: Arc<Vec<String>>,
+        #[sensitive]
+        cache: Arc<Mutex<Config>>,
+        #[sensitive]
+        settings: std::sync::Arc<std::sync::RwLock<Config>>,
+    }
+
+    let key = |api_key: &str| Config {
+        api_key: api_key.into(),
+    };
+    let config = Arc::new(key("sk_config"));
+    let cache = Arc::new(Mutex::new(key("sk_cache")));
+    let request = || Request {
+        config: Arc::clone(&config),
+        session: Rc::new(RefCell::new(key("sk_session"))),
+        tags: Arc::new(vec!["tag_value".into()]),
+        cache: Arc::clone(&cache),
+        settings: Arc::new(RwLock::new(key("sk_settings"))),
+    };
+
+    let redacted = request().redact();
+    assert_eq!(redacted.config.api_key, "[REDACTED]");
+    assert_eq!(redacted.session.borrow().api_key, "[REDACTED]");
+    assert_eq!(redacted.tags[0], "*****alue");
+    assert_eq!(redacted.cache.lock().unwrap().api_key, "[REDACTED]");
+    assert_eq!(redacted.settings.read().unwrap().api_key, "[REDACTED]");
+    // Shared originals are untouched
+    assert_eq!(config.api_key, "sk_config");
+    assert_eq!(cache.lock().unwrap().api_key, "sk_cache");
+
+    // Registry overrides reach values behind the locks
+    let registry = redaction::PolicyRegistry::new()
+        .with_policy::<Secret>(TextRedactionPolicy::full_with("<hidden>"));
+    let redacted = registry.redact(request());
+    assert_eq!(redacted.cache.lock().unwrap().api_key, "<hidden>");
+    assert_eq!(redacted.settings.read().unwrap().api_key, "<hidden>");
+}
+
 #[test]
 fn test_nested_box_traversal() {
     #[derive(Clone, Sensitive)]
@@ -538,6 +686,35 @@ fn test_scalar_types_comprehensive() {
     assert_eq!(redacted.char_val, 'X');
 }
 
+#[test]
+fn test_qualified_and_aliased_scalars() {
+    type Cents = i64;
+
+    #[derive(Clone, Sensitive)]
+    #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+    struct Balance {
+        #[sensitive]
+        account: std::primitive::u64,
+        #[sensitive]
+        frozen: ::core::primitive::bool,
+        #[sensitive(scalar)]
+        amount: Cents,
+        fee: Cents,
+    }
+
+    let redacted = Balance {
+        account: 12_345_678,
+        frozen: true,
+        amount: 10_000,
+        fee: 25,
+    }
+    .redact();
+    assert_eq!(redacted.account, 0);
+    assert!(!redacted.frozen);
+    assert_eq!(redacted.amount, 0);
+    assert_eq!(redacted.fee, 25);
+}
+
 #[test]
 fn test_mixed_named_and_sensitive_fields() {
     #[derive(Clone, Sensitive)]
@@ -750,3 +927,214 @@ fn test_nested_struct_requires_sensitive_annotation() {
     assert_eq!(redacted_unannotated.creds.password, "secret123"); // NOT redacted!
     assert_eq!(redacted_unannotated.creds.username, "alice");
 }
+
+#[test]
+fn test_container_default_classification() {
+    #[derive(Clone, Sensitive)]
+    #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+    #[sensitive(default = Secret)]
+    struct PatientRecord {
+        id: u64,
+        name: String,
+        aliases: Option<Vec<String>>,
+        #[sensitive(public)]
+        ward: String,
+        #[sensitive(Token)]
+        mrn: String,
+    }
+
+    #[derive(Clone, Sensitive)]
+    #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+    #[sensitive(default = Secret)]
+    enum Contact {
+        Phone(String),
+        Email {
+            address: String,
+            #[sensitive(public)]
+            label: String,
+        },
+    }
+
+    let record = PatientRecord {
+        id: 7,
+        name: "Jane Roe".into(),
+        aliases: Some(vec!["J. Roe".into()]),
+        ward: "Cardiology".into(),
+        mrn: "MRN-00012345".into(),
+    };
+    let redacted = record.redact();
+    assert_eq!(redacted.id, 7); // Not string-like, unchanged
+    assert_eq!(redacted.name, "[REDACTED]");
+    assert_eq!(redacted.aliases, Some(vec!["[REDACTED]".to_string()]));
+    assert_eq!(redacted.ward, "Cardiology"); // Explicit opt-out
+    assert_eq!(redacted.mrn, "********2345"); // Field override
+
+    let Contact::Phone(phone) = Contact::Phone("+1 555 0100".into()).redact() else {
+        unreachable!()
+    };
+    assert_eq!(phone, "[REDACTED]");
+    let Contact::Email { address, label } = Contact::Email {
+        address: "jane@example.com".into(),
+        label: "work".into(),
+    }
+    .redact() else {
+        unreachable!()
+    };
+    assert_eq!(address, "[REDACTED]");
+    assert_eq!(label, "work");
+}
+
+#[test]
+fn test_deny_unannotated_with_explicit_markers() {
+    // Every field is annotated, classified by the default, or marked public;
+    // removing any marker makes this fail to compile.
+    #[derive(Clone, Sensitive)]
+    #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+    #[sensitive(deny_unannotated, default = Secret)]
+    struct TaxFiling {
+        ssn: String,
+        #[sensitive]
+        income: u64,
+        #[sensitive(public)]
+        year: u16,
+    }
+
+    let redacted = TaxFiling {
+        ssn: "123-45-6789".into(),
+        income: 85_000,
+        year: 2024,
+    }
+    .redact();
+    assert_eq!(redacted.ssn, "[REDACTED]");
+    assert_eq!(redacted.income, 0);
+    assert_eq!(redacted.year, 2024);
+}
+
+#[test]
+fn test_strict_names_accepts_explicit_decisions() {
+    // Under `strict_names`, an unannotated `email` or `api_key` would not compile.
+    #[derive(Clone, Sensitive)]
+    #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+    #[sensitive(strict_names)]
+    struct Signup {
+        #[sensitive(Token)]
+        api_key: String,
+        #[sensitive(public)]
+        email: String,
+        token_type: String,
+    }
+
+    let redacted = Signup {
+        api_key: "sk_live_1234567890".into(),
+        email: "support@example.com".into(),
+        token_type: "Bearer".into(),
+    }
+    .redact();
+    assert_eq!(redacted.api_key, "**************7890");
+    assert_eq!(redacted.email, "support@example.com");
+    assert_eq!(redacted.token_type, "Bearer");
+}
+
+#[test]
+fn test_with_custom_redaction_function() {
+    // Simulates an external type that is neither `SensitiveValue` nor `Sensitive`
+    #[derive(Clone, Debug, PartialEq)]
+    #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+    struct ExternalUrl(String);
+
+    fn blank_bytes(bytes: Vec<u8>) -> Vec<u8> {
+        vec![0; bytes.len()]
+    }
+
+    fn strip_query(url: ExternalUrl) -> ExternalUrl {
+        ExternalUrl(url.0.split('?').next().unwrap_or_default().to_string())
+    }
+
+    #[derive(Clone, Sensitive)]
+    #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+    struct Upload {
+        #[sensitive(with = blank_bytes)]
+        payload: Vec<u8>,
+        #[sensitive(with = strip_query)]
+        source: ExternalUrl,
+    }
+
+    let redacted = Upload {
+        payload: vec![1, 2, 3],
+        source: ExternalUrl("https://cdn.example.com/a.png?sig=abc".into()),
+    }
+    .redact();
+    assert_eq!(redacted.payload, vec![0, 0, 0]);
+    assert_eq!(
+        redacted.source,
+        ExternalUrl("https://cdn.example.com/a.png".into())
+    );
+}
+
+#[test]
+fn test_replace_with_fixed_values() {
+    #[derive(Clone, Debug, Default, PartialEq)]
+    #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+    struct ExternalId([u8; 4]);
+
+    impl ExternalId {
+        fn nil() -> Self {
+            Self([0; 4])
+        }
+    }
+
+    #[derive(Clone, Sensitive)]
+    #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+    struct Order {
+        #[sensitive(replace = None)]
+        coupon: Option<ExternalId>,
+        #[sensitive(replace = ExternalId::nil())]
+        customer: ExternalId,
+        #[sensitive(replace = Default::default())]
+        notes: Vec<String>,
+        #[sensitive(replace = 1)]
+        quantity: u32,
+    }
+
+    let redacted = Order {
+        coupon: Some(ExternalId([9; 4])),
+        customer: ExternalId([1, 2, 3, 4]),
+        notes: vec!["leave at door".into()],
+        quantity: 7,
+    }
+    .redact();
+    assert_eq!(redacted.coupon, None);
+    assert_eq!(redacted.customer, ExternalId::nil());
+    assert!(redacted.notes.is_empty());
+    assert_eq!(redacted.quantity, 1);
+}
+
+#[test]
+fn test_drop_optional_fields() {
+    #[derive(Clone, Sensitive)]
+    #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+    enum Evidence {
+        Scan {
+            #[sensitive(drop)]
+            image: Option<Vec<u8>>,
+            pages: u32,
+        },
+        Biometric(#[sensitive(drop)] Option<Vec<u8>>),
+    }
+
+    let redacted = Evidence::Scan {
+        image: Some(vec![0xFF, 0xD8]),
+        pages: 2,
+    }
+    .redact();
+    assert!(matches!(
+        redacted,
+        Evidence::Scan {
+            image: None,
+            pages: 2
+        }
+    ));
+
+    let redacted = Evidence::Biometric(Some(vec![1, 2, 3])).redact();
+    assert!(matches!(redacted, Evidence::Biometric(None)));
+}
@@ -0,0 +1,10 @@
+//! Compile tests for the derive's sensitive-name warnings.
+//!
+//! The warning is a `deprecated` lint spanned at the field, so the fixtures
+//! deny that lint and assert on the compiler output.
+
+#[test]
+fn unannotated_sensitive_field_warns() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/unannotated_sensitive_field.rs");
+}
@@ -0,0 +1,89 @@
+//! End-to-end tests for free-text fields redacted by the scanner.
+//!
+//! These tests exercise `#[sensitive(scan)]` and the `FreeText`
+//! classification on plain strings and common wrappers.
+
+#![cfg(feature = "scanner")]
+
+use redaction::{FreeText, PolicyRegistry, Redactable, Sensitive, TextRedactionPolicy};
+
+#[derive(Clone, Sensitive)]
+#[cfg_attr(feature = "slog", derive(serde::Serialize))]
+struct SupportTicket {
+    id: u64,
+    #[sensitive(scan)]
+    description: String,
+    #[sensitive(FreeText)]
+    notes: Vec<String>,
+    #[sensitive(scan)]
+    resolution: Option<String>,
+}
+
+fn ticket() -> SupportTicket {
+    SupportTicket {
+        id: 7,
+        description: "Customer jane@example.com cannot log in".to_string(),
+        notes: vec![
+            "Charged card 4111 1111 1111 1111 twice".to_string(),
+            "No PII here".to_string(),
+        ],
+        resolution: Some("Reset from 192.168.0.12".to_string()),
+    }
+}
+
+#[test]
+fn test_scan_replaces_embedded_values_in_place() {
+    let redacted = ticket().redact();
+    assert_eq!(redacted.id, 7);
+    assert_eq!(
+        redacted.description,
+        "Customer ja************** cannot log in"
+    );
+    assert_eq!(
+        redacted.notes,
+        ["Charged card 411111******1111 twice", "No PII here"]
+    );
+    assert_eq!(
+        redacted.resolution.as_deref(),
+        Some("Reset from 192.168.0.0")
+    );
+}
+
+#[test]
+fn test_scan_finds_phone_numbers() {
+    for (text, expected) in [
+        ("call +44 20 7946 0958", "call **************58"),
+        ("call (555) 123-4567", "call ************67"),
+        ("call 555-123-4567", "call **********67"),
+    ] {
+        assert_eq!(
+            redaction::apply_classification::<FreeText, _>(text.to_string()),
+            expected
+        );
+    }
+}
+
+#[test]
+fn test_scan_leaves_dates_times_ids_and_ports_alone() {
+    for text in [
+        "deployed on 2024-06-15 at 10:30",
+        "order 123-456-789 shipped",
+        "port 8080 9090 7070",
+        "retry at 23:59:59 on 15/06/2024",
+        "ids 1234 5678 9012 done",
+    ] {
+        assert_eq!(
+            redaction::apply_classification::<FreeText, _>(text.to_string()),
+            text
+        );
+    }
+}
+
+#[test]
+fn test_free_text_policy_can_be_overridden() {
+    let registry =
+        PolicyRegistry::new().with_policy::<FreeText>(TextRedactionPolicy::full_with("<text>"));
+    let redacted = registry.redact(ticket());
+    assert_eq!(redacted.description, "<text>");
+    assert_eq!(redacted.notes, ["<text>", "<text>"]);
+}
@@ -679,6 +679,34 @@ fn test_into_redacted_json_no_sensitive_fields() {
     }
 }
 
+#[test]
+fn test_into_redacted_json_omits_dropped_fields() {
+    #[derive(Clone, Sensitive, Serialize)]
+    struct IdentityCheck {
+        #[sensitive(Secret)]
+        document_number: String,
+        #[sensitive(drop)]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        document_scan: Option<Vec<u8>>,
+    }
+
+    let check = IdentityCheck {
+        document_number: "X1234567".into(),
+        document_scan: Some(vec![0xFF; 64]),
+    };
+
+    let redacted = check.into_redacted_json();
+    let mut serializer = CapturingSerializer::new();
+    serialize_to_capture(&redacted, "check", &mut serializer);
+
+    if let Some(CapturedValue::Serde(json)) = serializer.get("check") {
+        assert_eq!(json["document_number"], "[REDACTED]");
+        assert!(json.get("document_scan").is_none());
+    } else {
+        panic!("Expected Serde value");
+    }
+}
+
 // ============================================================================
 // Custom classification tests
 // ============================================================================
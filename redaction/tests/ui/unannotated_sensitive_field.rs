@@ -0,0 +1,13 @@
+// The heuristic warning is a `deprecated` lint, so denying that lint turns it
+// into an error trybuild can check. `-A deprecated` silences it the same way.
+#![deny(deprecated)]
+
+use redaction::Sensitive;
+
+#[derive(Clone, Sensitive, serde::Serialize)]
+struct Login {
+    username: String,
+    password: String,
+}
+
+fn main() {}